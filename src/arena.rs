@@ -20,11 +20,39 @@
 //! A typed arena that allocates items in non-amortized constant time.
 
 use super::iter::{IntoIter, Iter, IterMut, Position};
-use super::manually_drop::ManuallyDropArena;
+#[cfg(feature = "stats")]
+use super::manually_drop::Stats;
+use super::manually_drop::{AllocFailureKind, ManuallyDropArena};
+use super::options::{TrackBorrowsPriv, TypedOptions};
 use super::ArenaOptions;
+use alloc::vec::Vec;
 use core::cell::UnsafeCell;
-use core::mem::ManuallyDrop;
-use integral_constant::Bool;
+use core::hash::{Hash, Hasher};
+use core::iter::{self, FromIterator};
+use core::mem::{self, ManuallyDrop};
+use core::ops::{Index, IndexMut};
+use core::pin::Pin;
+use core::ptr;
+#[cfg(feature = "serde")]
+use core::{fmt, marker::PhantomData};
+use integral_constant::{Bool, Usize};
+#[cfg(feature = "serde")]
+use serde::de::{self, Deserialize, Deserializer, SeqAccess};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+mod cursor;
+pub use cursor::CursorMut;
+mod handle;
+pub use handle::Ref;
+mod tracked;
+pub use tracked::ArenaRefMut;
+
+type TrackBorrows<T, Options> = <Options as ArenaOptions<T>>::TrackBorrows;
+type Borrows<T, Options> =
+    <TrackBorrows<T, Options> as TrackBorrowsPriv>::Borrows;
 
 /// An arena that allocates items of type `T` in non-amortized O(1) (constant)
 /// time.
@@ -37,10 +65,15 @@ use integral_constant::Bool;
 /// The arena may panic when created or used if [`mem::size_of::<T>()`][size]
 /// times [`Options::ChunkSize`] is greater than [`usize::MAX`].
 ///
+/// Allocation methods that don't start with `try_` panic if the arena has
+/// already allocated [`Options::MaxChunks`] chunks and they are all full.
+///
 /// [`Options::ChunkSize`]: ArenaOptions::ChunkSize
+/// [`Options::MaxChunks`]: ArenaOptions::MaxChunks
 /// [size]: core::mem::size_of
 pub struct Arena<T, Options: ArenaOptions<T> = super::Options>(
     ManuallyDrop<UnsafeCell<ManuallyDropArena<T, Options>>>,
+    Borrows<T, Options>,
 );
 
 impl<T, Options: ArenaOptions<T>> Default for Arena<T, Options> {
@@ -52,7 +85,54 @@ impl<T, Options: ArenaOptions<T>> Default for Arena<T, Options> {
 impl<T, Options: ArenaOptions<T>> Arena<T, Options> {
     /// Creates a new [`Arena`].
     pub fn new() -> Self {
-        Self(Default::default())
+        Self(Default::default(), Default::default())
+    }
+
+    /// Creates a new arena containing the items in `vec`, in order.
+    ///
+    /// Space for all of the items is reserved up front, and the items are
+    /// moved into the arena a chunk at a time. The vector's heap buffer is
+    /// freed afterward.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let inner = ManuallyDropArena::from_vec(vec);
+        // SAFETY: `inner` was just created, so no references to its items
+        // exist.
+        unsafe { Self::from_manually_drop(inner) }
+    }
+
+    /// Wraps a [`ManuallyDropArena`] in an [`Arena`].
+    ///
+    /// # Safety
+    ///
+    /// Same requirements as [`ManuallyDropArena::into_arena`].
+    pub(crate) unsafe fn from_manually_drop(
+        inner: ManuallyDropArena<T, Options>,
+    ) -> Self {
+        Self(ManuallyDrop::new(UnsafeCell::new(inner)), Default::default())
+    }
+
+    /// Unwraps the [`ManuallyDropArena`] in this arena. The items won't be
+    /// dropped unless [`ManuallyDropArena::drop`] is called.
+    pub(crate) fn into_manually_drop(self) -> ManuallyDropArena<T, Options> {
+        let mut this = ManuallyDrop::new(self);
+
+        // SAFETY: This `ManuallyDrop` won't be used again because we moved
+        // `self` into a `ManuallyDrop` (so its destructor won't run).
+        let inner = unsafe { ManuallyDrop::take(&mut this.0) };
+        inner.into_inner()
+    }
+
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    fn check_borrows(&self) {
+        TrackBorrows::<T, Options>::check(&self.1);
     }
 
     fn inner(&self) -> &ManuallyDropArena<T, Options> {
@@ -62,6 +142,14 @@ impl<T, Options: ArenaOptions<T>> Arena<T, Options> {
         unsafe { &*self.0.get() }
     }
 
+    /// Returns cumulative allocation statistics for this arena.
+    ///
+    /// This method is available only with the crate feature `stats`.
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> Stats {
+        self.inner().stats()
+    }
+
     /// Returns the total number of items that have been allocated.
     pub fn len(&self) -> usize {
         self.inner().len()
@@ -72,6 +160,25 @@ impl<T, Options: ArenaOptions<T>> Arena<T, Options> {
         self.len() == 0
     }
 
+    /// Returns the number of chunks the arena has allocated, including spare
+    /// chunks allocated by [`Self::reserve`].
+    pub fn chunk_count(&self) -> usize {
+        self.inner().chunk_count()
+    }
+
+    /// Returns the number of items the arena can hold without allocating
+    /// another chunk. This is 0 if the arena hasn't allocated any chunks.
+    pub fn capacity(&self) -> usize {
+        self.inner().capacity()
+    }
+
+    /// Returns the number of bytes of memory the arena has requested from the
+    /// global allocator for its chunks. See
+    /// [`ManuallyDropArena::bytes_allocated`].
+    pub fn bytes_allocated(&self) -> usize {
+        self.inner().bytes_allocated()
+    }
+
     /// Allocates a new item in the arena and initializes it with `value`.
     /// Returns a reference to the allocated item.
     ///
@@ -82,7 +189,7 @@ impl<T, Options: ArenaOptions<T>> Arena<T, Options> {
     #[allow(clippy::mut_from_ref)]
     pub fn alloc(&self, value: T) -> &mut T
     where
-        Options: ArenaOptions<T, Mutable = Bool<true>>,
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
     {
         // SAFETY: `ManuallyDropArena::alloc` does not run any code that could
         // possibly call any methods of `Self`, which ensures that we do not
@@ -101,12 +208,264 @@ impl<T, Options: ArenaOptions<T>> Arena<T, Options> {
     #[allow(clippy::mut_from_ref)]
     pub fn try_alloc(&self, value: T) -> Option<&mut T>
     where
-        Options: ArenaOptions<T, Mutable = Bool<true>>,
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
     {
         // SAFETY: See `Self::alloc`.
         unsafe { &mut *self.0.get() }.try_alloc(value)
     }
 
+    /// Like [`Self::try_alloc`], but reports why the allocation failed.
+    ///
+    /// # Errors
+    ///
+    /// If the item can't be allocated, returns `value` (which is not written
+    /// to the arena) along with an [`AllocFailureKind`] describing the
+    /// failure.
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_detailed(
+        &self,
+        value: T,
+    ) -> Result<&mut T, (T, AllocFailureKind)>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+    {
+        // SAFETY: See `Self::alloc`.
+        unsafe { &mut *self.0.get() }.try_alloc_detailed(value)
+    }
+
+    /// Allocates `values` as consecutive items in a single chunk and returns
+    /// a reference to them as an array.
+    ///
+    /// If the current chunk has some free space, but not enough for `N`
+    /// items, the items are placed at the start of the next chunk, and the
+    /// rest of the current chunk is left unused until the arena is cleared or
+    /// shrunk. Allocating arrays of a length that divides
+    /// [`Options::ChunkSize`] avoids wasting space this way, as long as no
+    /// other items are allocated in between.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is greater than [`Options::ChunkSize`], or if a new
+    /// chunk is needed and the arena has already allocated
+    /// [`Options::MaxChunks`] chunks.
+    ///
+    /// [`Options::ChunkSize`]: ArenaOptions::ChunkSize
+    /// [`Options::MaxChunks`]: ArenaOptions::MaxChunks
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_array<const N: usize>(&self, values: [T; N]) -> &mut [T; N]
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+    {
+        // SAFETY: See `Self::alloc`. `ManuallyDropArena::alloc_array_ptr`
+        // returns a pointer to initialized, properly aligned items.
+        unsafe { (*self.0.get()).alloc_array_ptr(values).as_mut() }
+    }
+
+    /// Like [`Self::alloc_array`], but returns [`None`] if memory allocation
+    /// fails or the chunk limit would be exceeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is greater than [`Options::ChunkSize`].
+    ///
+    /// [`Options::ChunkSize`]: ArenaOptions::ChunkSize
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_array<const N: usize>(
+        &self,
+        values: [T; N],
+    ) -> Option<&mut [T; N]>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+    {
+        // SAFETY: See `Self::alloc`.
+        let mut ptr = unsafe { (*self.0.get()).try_alloc_array_ptr(values) }?;
+        // SAFETY: See `Self::alloc_array`.
+        Some(unsafe { ptr.as_mut() })
+    }
+
+    /// Allocates a new item in a pinned arena (see [`Options::Pinned`]) and
+    /// initializes it with `value`. Returns a pinned reference to the
+    /// allocated item.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`Options::Pinned`]: ArenaOptions::Pinned
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_pin(&self, value: T) -> Pin<&mut T>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<true>>,
+    {
+        // SAFETY: See `Self::alloc`. `ManuallyDropArena::alloc_ptr` returns
+        // a pointer to an initialized, properly aligned item.
+        let item = unsafe { (*self.0.get()).alloc_ptr(value).as_mut() };
+        // SAFETY: Because `Options::Pinned` is true, no safe method of this
+        // type moves items or provides unpinned mutable references to them,
+        // and the arena drops its items before freeing their memory.
+        unsafe { Pin::new_unchecked(item) }
+    }
+
+    /// Allocates a new item in the arena, initializes it with the result of
+    /// `f`, and returns a reference to it.
+    ///
+    /// Like [`ManuallyDropArena::alloc_with`], the item's slot is located
+    /// before `f` is called, and the result of `f` is written directly into
+    /// it. `f` may allocate more items in this arena; they are placed after
+    /// this item.
+    ///
+    /// If `f` panics, no item is allocated. If `f` allocated items in this
+    /// arena before panicking, though, every item in the arena is leaked and
+    /// the arena is left empty, since the slot can't be freed without leaving
+    /// a gap between items.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_with<F>(&self, f: F) -> &mut T
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+        F: FnOnce() -> T,
+    {
+        // SAFETY: `self.0.get()` is valid for the lifetime of `self`, and no
+        // references to the `ManuallyDropArena` are kept while `f` runs.
+        // Because `Options::Mutable` is true, items can't be read through
+        // `&self`, so `f` can't read the uninitialized slot.
+        // `ManuallyDropArena::alloc_ptr_with` returns an initialized,
+        // properly aligned pointer, and no other references to the item
+        // exist.
+        unsafe { ManuallyDropArena::alloc_ptr_with(self.0.get(), f).as_mut() }
+    }
+
+    /// If `condition` is true, allocates a new item in the arena, initializes
+    /// it with the result of `f`, and returns a reference to it. Otherwise,
+    /// returns [`None`] without calling `f`.
+    ///
+    /// The item is constructed in place, as with [`Self::alloc_with`].
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_if<F>(&self, condition: bool, f: F) -> Option<&mut T>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+        F: FnOnce() -> T,
+    {
+        condition.then(|| self.alloc_with(f))
+    }
+
+    /// Allocates every item yielded by `iter` and returns references to the
+    /// allocated items, in order.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn extend_refs<I>(&self, iter: I) -> Vec<&mut T>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let mut refs = Vec::with_capacity(iter.size_hint().0);
+        refs.extend(iter.map(|item| self.alloc(item)));
+        refs
+    }
+
+    /// Allocates every item yielded by `iter`, in order, and returns the
+    /// number of items allocated.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn alloc_extend<I>(&self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut count = 0;
+        for item in iter {
+            // SAFETY: See `Self::alloc`.
+            unsafe { &mut *self.0.get() }.alloc_ptr(item);
+            count += 1;
+        }
+        count
+    }
+
+    /// Allocates every item yielded by `iter`, in order, and returns a slice
+    /// containing the allocated items, or returns [`None`] (without
+    /// allocating any items or advancing `iter`) if the items wouldn't fit in
+    /// a single chunk.
+    ///
+    /// The items fit if there is room for [`ExactSizeIterator::len`] items in
+    /// the rest of the chunk that the next item would be allocated in. At
+    /// most that many items are taken from `iter`.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` allocates items in this arena.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_from_iter<I>(&self, iter: I) -> Option<&mut [T]>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let len = iter.len();
+        if len > self.inner().contiguous_free() {
+            return None;
+        }
+
+        let base_len = self.len();
+        let mut start = None;
+        let mut count = 0;
+        for item in iter.take(len) {
+            // SAFETY: See `Self::alloc`.
+            let ptr = unsafe { &mut *self.0.get() }.alloc_ptr(item);
+            count += 1;
+            assert!(
+                self.len() == base_len + count,
+                "`iter` allocated items in this arena",
+            );
+            start.get_or_insert(ptr);
+        }
+
+        Some(match start {
+            // SAFETY: The `count` items starting at `start` were just
+            // allocated, and no other items were allocated in between, so
+            // they're in the same chunk (they fit in its free space) and are
+            // contiguous. No other references to them exist.
+            Some(start) => unsafe {
+                core::slice::from_raw_parts_mut(start.as_ptr(), count)
+            },
+            None => &mut [],
+        })
+    }
+
+    /// Allocates a new item in the arena, initializes it with `value`, and
+    /// calls `f` with a reference to the item. Returns the result of `f`.
+    ///
+    /// Unlike [`Self::alloc`], the reference to the item does not outlive the
+    /// call to `f`, so the arena is not left borrowed afterward.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn alloc_scoped<R, F>(&self, value: T, f: F) -> R
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+        F: FnOnce(&mut T) -> R,
+    {
+        f(self.alloc(value))
+    }
+
     /// Allocates a new item in the arena and initializes it with `value`.
     /// Returns a shared/immutable reference to the allocated item.
     ///
@@ -126,14 +485,353 @@ impl<T, Options: ArenaOptions<T>> Arena<T, Options> {
         unsafe { &mut *self.0.get() }.try_alloc_shared(value)
     }
 
+    /// Like [`Self::try_alloc_shared`], but reports why the allocation failed.
+    ///
+    /// # Errors
+    ///
+    /// If the item can't be allocated, returns `value` (which is not written
+    /// to the arena) along with an [`AllocFailureKind`] describing the
+    /// failure.
+    pub fn try_alloc_shared_detailed(
+        &self,
+        value: T,
+    ) -> Result<&T, (T, AllocFailureKind)> {
+        // SAFETY: See `Self::alloc_shared`.
+        unsafe { &mut *self.0.get() }.try_alloc_shared_detailed(value)
+    }
+
+    /// Allocates a new item in the arena and initializes it with `value`.
+    /// Returns an index-based handle to the allocated item, which can be
+    /// dereferenced with [`Self::deref_handle`] or
+    /// [`Self::deref_handle_mut`].
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn alloc_handle(&self, value: T) -> Ref<T> {
+        let index = self.len();
+        self.alloc_shared(value);
+        Ref::new(index)
+    }
+
+    /// Drops the items in this arena but keeps its chunks, so that later
+    /// allocations reuse them instead of allocating new chunks. All existing
+    /// [`Position`]s for this arena are invalidated, unless the arena was
+    /// already empty.
+    ///
+    /// If an item's destructor panics, the remaining items are still dropped
+    /// before the panic is propagated. Either way, the arena is left empty.
+    pub fn clear(&mut self) {
+        // SAFETY: This type's design guarantees no references to items exist.
+        unsafe {
+            self.0.get_mut().clear();
+        }
+    }
+
+    /// Reserves space for at least `additional` more items, so that the next
+    /// `additional` allocations won't need to allocate new chunks.
+    ///
+    /// Reserving space never moves existing items, so references returned by
+    /// [`Self::alloc`] remain usable:
+    ///
+    /// ```
+    /// # use fixed_typed_arena::Arena;
+    /// let arena = Arena::<u8, 4>::new();
+    /// let item = arena.alloc(1);
+    /// arena.reserve(100);
+    /// *item += 1;
+    /// # assert_eq!(*item, 2);
+    /// ```
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails;
+    /// for a version that returns [`None`], see [`Self::try_reserve`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if reserving the space would require more than
+    /// [`ArenaOptions::MaxChunks`] chunks.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn reserve(&self, additional: usize) {
+        // SAFETY: `ManuallyDropArena::reserve` does not run any code that
+        // could possibly call any methods of `Self`, which ensures that we do
+        // not borrow the data in the `UnsafeCell` multiple times concurrently.
+        //
+        // Additionally, reserving space only links new chunks after the tail;
+        // it doesn't move, change, or reuse any existing items, so existing
+        // item references remain valid.
+        unsafe { &mut *self.0.get() }.reserve(additional);
+    }
+
+    /// Like [`Self::reserve`], but returns [`None`] if memory allocation
+    /// fails or the chunk limit would be exceeded. Any chunks allocated
+    /// before the failure are kept for later allocations.
+    pub fn try_reserve(&self, additional: usize) -> Option<()> {
+        // SAFETY: See `Self::reserve`.
+        unsafe { &mut *self.0.get() }.try_reserve(additional)
+    }
+
+    /// Allocates items yielded by `iter` until the iterator is exhausted or
+    /// the arena can't hold any more items (because memory allocation fails
+    /// or the chunk limit is reached). Returns the iterator, from which no
+    /// items are lost: space for each item is reserved before the item is
+    /// taken from the iterator.
+    ///
+    /// If the iterator is exhausted when a new chunk has just been reserved,
+    /// the chunk is kept for later allocations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` itself allocates items in this arena and in doing so
+    /// uses up space that was reserved for the next item, and no more space
+    /// can be reserved.
+    pub fn extend_until_full<I>(&self, iter: I) -> I::IntoIter
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        while self.try_reserve(1).is_some() {
+            let item = if let Some(item) = iter.next() {
+                item
+            } else {
+                break;
+            };
+            self.alloc_shared(item);
+        }
+        iter
+    }
+
+    /// Allocates a new item in the arena and initializes it with `value`.
+    /// Returns a guard that provides mutable access to the allocated item.
+    ///
+    /// While the guard is alive, methods that provide immutable access to
+    /// existing items, like [`Self::iter`], panic. See
+    /// [`Options::TrackBorrows`].
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn alloc_tracked(&self, value: T) -> ArenaRefMut<'_, T>
+    where
+        Options: ArenaOptions<
+            T,
+            Mutable = Bool<false>,
+            TrackBorrows = Bool<true>,
+            Pinned = Bool<false>,
+        >,
+    {
+        // SAFETY: See `Self::alloc`.
+        let mut item = unsafe { &mut *self.0.get() }.alloc_ptr(value);
+        // This can't overflow, as each guard refers to a distinct item, and
+        // the arena can't hold more than `usize::MAX` items.
+        self.1.set(self.1.get() + 1);
+
+        // SAFETY: `ManuallyDropArena::alloc_ptr` returns initialized,
+        // properly aligned pointers. Because `Options::Mutable` is false, no
+        // other mutable references to the new item exist, and immutable
+        // references to existing items can't be created until the guard is
+        // dropped. Iterators created before this call stop before the new
+        // item.
+        ArenaRefMut::new(unsafe { item.as_mut() }, &self.1)
+    }
+
+    /// Allocates every item of every array yielded by `iter`, in order.
+    /// Returns the number of items allocated.
+    ///
+    /// Space for each array is reserved before its items are allocated, so
+    /// the items of a single array are never split by a failed allocation.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn alloc_flatten<I, const N: usize>(&self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = [T; N]>,
+    {
+        let mut count = 0;
+        for array in iter {
+            // SAFETY: `ManuallyDropArena::reserve` does not run any code that
+            // could possibly call any methods of `Self` and does not touch
+            // existing items.
+            unsafe { &mut *self.0.get() }.reserve(N);
+            for item in IntoIterator::into_iter(array) {
+                self.alloc_shared(item);
+            }
+            count += N;
+        }
+        count
+    }
+
+    /// Returns a reference to the item at index `index`, or [`None`] if
+    /// `index` is out of bounds.
+    ///
+    /// This method runs in O(`index / CHUNK_SIZE`) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    pub fn get(&self, index: usize) -> Option<&T>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        self.check_borrows();
+        let item = self.inner().get_ptr(index)?;
+        // SAFETY: `ManuallyDropArena::get_ptr` returns initialized, properly
+        // aligned pointers. Because `Options::Mutable` is false, mutable
+        // references to items can only be obtained through `ArenaRefMut`
+        // guards, and we checked that none are alive.
+        Some(unsafe { item.as_ref() })
+    }
+
+    /// Returns a mutable reference to the item at index `index`, or [`None`]
+    /// if `index` is out of bounds.
+    ///
+    /// This method runs in O(`index / CHUNK_SIZE`) time.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T>
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        let mut item = self.inner().get_ptr(index)?;
+        // SAFETY: `ManuallyDropArena::get_ptr` returns initialized, properly
+        // aligned pointers, and this type's design guarantees no other
+        // references to items exist.
+        Some(unsafe { item.as_mut() })
+    }
+
+    /// Returns a reference to the item that `handle` refers to.
+    ///
+    /// This method runs in O(`handle.index() / CHUNK_SIZE`) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` is out of bounds (e.g., if it came from a different
+    /// arena), or if [`Options::TrackBorrows`] is true and any
+    /// [`ArenaRefMut`] guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    pub fn deref_handle(&self, handle: Ref<T>) -> &T
+    where
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        &self[handle.index()]
+    }
+
+    /// Returns mutable references to the items at indices `a` and `b`, or
+    /// [`None`] if `a` and `b` are equal or either is out of bounds.
+    ///
+    /// This method runs in O(`max(a, b) / CHUNK_SIZE`) time.
+    pub fn get_two_mut(
+        &mut self,
+        a: usize,
+        b: usize,
+    ) -> Option<(&mut T, &mut T)>
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        if a == b {
+            return None;
+        }
+        let inner = self.inner();
+        let (mut a, mut b) = (inner.get_ptr(a)?, inner.get_ptr(b)?);
+        // SAFETY: `ManuallyDropArena::get_ptr` returns initialized, properly
+        // aligned pointers. `a` and `b` are different indices, so the
+        // pointers refer to different items, and this type's design
+        // guarantees no other references to items exist.
+        Some(unsafe { (a.as_mut(), b.as_mut()) })
+    }
+
+    /// Returns a mutable reference to the item that `handle` refers to.
+    ///
+    /// This method runs in O(`handle.index() / CHUNK_SIZE`) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` is out of bounds (e.g., if it came from a different
+    /// arena).
+    #[track_caller]
+    pub fn deref_handle_mut(&mut self, handle: Ref<T>) -> &mut T
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        let len = self.len();
+        self.get_mut(handle.index()).unwrap_or_else(|| {
+            index_out_of_bounds(handle.index(), len);
+        })
+    }
+
+    /// Returns a mutable reference to the item at index `index`, first
+    /// allocating default items until the arena contains more than `index`
+    /// items.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena can't hold `index + 1` items (e.g., if `index` is
+    /// [`usize::MAX`]), or if the items would require more than
+    /// [`ArenaOptions::MaxChunks`] chunks.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn ensure_index(&mut self, index: usize) -> &mut T
+    where
+        T: Default,
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        let needed = index.checked_add(1).unwrap_or_else(|| {
+            panic!("arena cannot hold more than `usize::MAX` items");
+        });
+        let inner = self.0.get_mut();
+        if let Some(additional) = needed.checked_sub(inner.len()) {
+            inner.reserve(additional);
+            for _ in 0..additional {
+                inner.alloc_shared(T::default());
+            }
+        }
+        self.get_mut(index).unwrap_or_else(|| {
+            unreachable!("`index` should be in bounds");
+        })
+    }
+
     /// Returns an iterator over the items in this arena.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
     pub fn iter(&self) -> Iter<'_, T, Options>
     where
         Options: ArenaOptions<T, Mutable = Bool<false>>,
     {
+        self.check_borrows();
         self.inner().iter()
     }
 
+    /// Returns an iterator over the items in this arena, along with their
+    /// indices (`(0, &item0), (1, &item1), ...`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    pub fn iter_indexed(&self) -> iter::Enumerate<Iter<'_, T, Options>>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        self.iter().enumerate()
+    }
+
     /// Returns an iterator over the items in this arena.
     ///
     /// # Safety
@@ -147,25 +845,271 @@ impl<T, Options: ArenaOptions<T>> Arena<T, Options> {
     }
 
     /// Returns a mutable iterator over the items in this arena.
-    pub fn iter_mut(&mut self) -> IterMut<'_, T, Options> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, Options>
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
         // SAFETY: This type's design guarantees no references to items exist.
         unsafe { self.0.get_mut().iter_mut_unchecked() }
     }
+
+    /// Returns an iterator over mutable slices of the items in each chunk,
+    /// in order. Every yielded slice is non-empty.
+    pub fn chunks_mut(&mut self) -> impl Iterator<Item = &mut [T]>
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        // SAFETY: This type's design guarantees no references to items exist.
+        unsafe { self.0.get_mut().chunks_mut_unchecked() }
+    }
+
+    /// Sorts the items in this arena by the key returned by `f`, so that items
+    /// with equal keys are stored next to each other. The sort is stable.
+    ///
+    /// The items are moved between the arena's existing slots; no chunks are
+    /// allocated or freed. All existing [`Position`]s for this arena are
+    /// invalidated.
+    ///
+    /// This method temporarily allocates a buffer for all of the items. If `f`
+    /// panics, the arena is left empty and its items are dropped.
+    ///
+    /// Because this method moves items, it takes `&mut self`, so it can't be
+    /// called while references returned by [`Self::alloc`] are in use:
+    ///
+    /// ```compile_fail
+    /// # use fixed_typed_arena::Arena;
+    /// let mut arena = Arena::<u8, 4>::new();
+    /// let item = arena.alloc(1);
+    /// arena.reorder_by_key(|&n| n); // Error: `arena` is borrowed
+    /// *item += 1;
+    /// ```
+    pub fn reorder_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        let inner = self.0.get_mut();
+        let len = inner.len();
+        let mut items = Vec::<T>::with_capacity(len);
+        for (start, count) in inner.chunk_starts() {
+            // SAFETY: `ManuallyDropArena::chunk_starts` returns pointers to
+            // `count` initialized items, and `items` has room for all `len`
+            // items in the arena.
+            unsafe {
+                let dst = items.as_mut_ptr().add(items.len());
+                ptr::copy_nonoverlapping(start, dst, count);
+                items.set_len(items.len() + count);
+            }
+        }
+
+        // SAFETY: The items have been moved into `items`, so the arena must no
+        // longer own them. This type's design guarantees no references to
+        // items or iterators exist.
+        unsafe {
+            inner.set_len(0);
+        }
+        items.sort_by_key(f);
+
+        // SAFETY: The arena previously held `len` items, so it has at least
+        // `len` slots. Nothing between `set_len` and the copies below can
+        // panic, and each copy initializes the slots it reports, so every
+        // slot up to `len` is initialized before the arena is used again.
+        unsafe {
+            inner.set_len(len);
+        }
+        let mut src = items.as_ptr();
+        for (start, count) in inner.chunk_starts() {
+            // SAFETY: `src` points to the remaining initialized items in
+            // `items`, and `ManuallyDropArena::chunk_starts` returns pointers
+            // to `count` slots, for `len` slots in total.
+            unsafe {
+                ptr::copy_nonoverlapping(src, start, count);
+                src = src.add(count);
+            }
+        }
+
+        // SAFETY: The items have been moved back into the arena.
+        unsafe {
+            items.set_len(0);
+        }
+    }
+
+    /// Retains only the items for which `keep` returns true, preserving
+    /// their order.
+    ///
+    /// `scratch` is used as a second buffer: its items are dropped, the
+    /// retained items are moved into it, and then it is swapped with `self`.
+    /// Both arenas keep their chunks, so calling this method repeatedly with
+    /// the same scratch arena doesn't allocate new chunks once the arenas are
+    /// large enough. All existing [`Position`]s for both arenas are
+    /// invalidated.
+    ///
+    /// If `keep` panics, the remaining items are dropped.
+    pub fn retain<F>(&mut self, scratch: &mut Self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        struct Guard<'a, T, Options: ArenaOptions<T>> {
+            iter: IterMut<'a, T, Options>,
+            arena: &'a mut ManuallyDropArena<T, Options>,
+        }
+
+        impl<T, Options: ArenaOptions<T>> Drop for Guard<'_, T, Options> {
+            fn drop(&mut self) {
+                for item in &mut self.iter {
+                    // SAFETY: The items yielded by `self.iter` haven't been
+                    // moved out, and they won't be accessed again.
+                    unsafe {
+                        ptr::drop_in_place(item);
+                    }
+                }
+                // SAFETY: Every item has been moved out or dropped, and
+                // `self.iter` is exhausted.
+                unsafe {
+                    self.arena.set_len(0);
+                }
+            }
+        }
+
+        // SAFETY: This type's design guarantees no references to items exist.
+        unsafe {
+            scratch.0.get_mut().clear();
+        }
+        mem::swap(self, scratch);
+
+        let arena = scratch.0.get_mut();
+        let mut guard = Guard {
+            // SAFETY: This type's design guarantees no references to items
+            // exist.
+            iter: unsafe { arena.iter_mut_unchecked() },
+            arena,
+        };
+        for item in &mut guard.iter {
+            // SAFETY: Each item is yielded only once, and `guard` forgets all
+            // of the items once the iterator is exhausted, so this item won't
+            // be dropped again.
+            let item = unsafe { ptr::read(item) };
+            if keep(&item) {
+                self.alloc_shared(item);
+            }
+        }
+    }
+
+    /// Returns a cursor over the items in this arena, starting at the first
+    /// item. The cursor can move both forward and backward.
+    ///
+    /// This method runs in O(`len / CHUNK_SIZE`) time.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T, Options>
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        // SAFETY: This type's design guarantees no references to items exist.
+        unsafe { CursorMut::new(self.0.get_mut()) }
+    }
+
+    /// Returns an iterator over disjoint pairs of adjacent items: the first
+    /// and second items, the third and fourth items, and so on. If the arena
+    /// contains an odd number of items, the last item is not yielded.
+    pub fn pairs_mut(&mut self) -> impl Iterator<Item = (&mut T, &mut T)> + '_
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        let mut iter = self.iter_mut();
+        iter::from_fn(move || Some((iter.next()?, iter.next()?)))
+    }
+
+    /// Moves the items in this arena, in order, into a new arena with a
+    /// chunk size of `NEW` and the same options otherwise, including
+    /// [`ArenaOptions::Allocator`].
+    ///
+    /// Space for all of the items is reserved in the new arena up front, and
+    /// each chunk of this arena is freed once its items have been moved.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `NEW` is 0 and this arena isn't empty, or if the items
+    /// would require more than [`ArenaOptions::MaxChunks`] chunks of size
+    /// `NEW`.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    #[allow(clippy::type_complexity)]
+    #[rustfmt::skip]
+    pub fn rechunk<const NEW: usize>(self) -> Arena<T, TypedOptions<
+        Usize<NEW>,
+        Options::SupportsPositions,
+        Options::Mutable,
+        Options::MaxChunks,
+        Options::TrackBorrows,
+        Options::Pinned,
+        Options::Allocator,
+    >>
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        let arena = Arena::new();
+        arena.reserve(self.len());
+        for item in self {
+            arena.alloc_shared(item);
+        }
+        arena
+    }
 }
 
 impl<T, Options> Arena<T, Options>
 where
     Options: ArenaOptions<T, SupportsPositions = Bool<true>>,
 {
+    /// Returns a [`Position`] referring to the item at index `index`. See
+    /// [`ManuallyDropArena::position_at_index`].
+    pub fn position_at_index(&self, index: usize) -> Option<Position> {
+        self.inner().position_at_index(index)
+    }
+
+    /// Like [`Self::alloc`], but also returns a [`Position`] referring to the
+    /// allocated item.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_with_position(&self, value: T) -> (&mut T, Position)
+    where
+        Options: ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+    {
+        // SAFETY: See `Self::alloc`.
+        unsafe { &mut *self.0.get() }.alloc_with_position(value)
+    }
+
+    /// Returns the number of items between positions `a` and `b`. See
+    /// [`ManuallyDropArena::position_distance`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` does not refer to a position in this arena.
+    pub fn position_distance(&self, a: &Position, b: &Position) -> usize {
+        self.inner().position_distance(a, b)
+    }
+
     /// Returns an iterator starting at the specified position.
     ///
     /// # Panics
     ///
     /// May panic if `position` does not refer to a position in this arena.
+    ///
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
     pub fn iter_at(&self, position: &Position) -> Iter<'_, T, Options>
     where
         Options: ArenaOptions<T, Mutable = Bool<false>>,
     {
+        self.check_borrows();
         self.inner().iter_at(position)
     }
 
@@ -194,10 +1138,104 @@ where
     pub fn iter_mut_at(
         &mut self,
         position: &Position,
-    ) -> IterMut<'_, T, Options> {
+    ) -> IterMut<'_, T, Options>
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
         // SAFETY: This type's design guarantees no references to items exist.
         unsafe { self.0.get_mut().iter_mut_at_unchecked(position) }
     }
+
+    /// Returns a reference to the item at the specified position, or [`None`]
+    /// if the position is at the end of the arena.
+    ///
+    /// This method runs in constant time.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `position` does not refer to a position in this arena.
+    ///
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    pub fn get_at(&self, position: &Position) -> Option<&T>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        self.check_borrows();
+        let item = self.inner().ptr_at(position)?;
+        // SAFETY: `ManuallyDropArena::ptr_at` returns initialized, properly
+        // aligned pointers. Because `Options::Mutable` is false, mutable
+        // references to items can only be obtained through `ArenaRefMut`
+        // guards, and we checked that none are alive.
+        Some(unsafe { item.as_ref() })
+    }
+
+    /// Returns a mutable reference to the item at the specified position, or
+    /// [`None`] if the position is at the end of the arena.
+    ///
+    /// This method runs in constant time.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `position` does not refer to a position in this arena.
+    pub fn get_at_mut(&mut self, position: &Position) -> Option<&mut T>
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        let mut item = self.inner().ptr_at(position)?;
+        // SAFETY: `ManuallyDropArena::ptr_at` returns initialized, properly
+        // aligned pointers, and this type's design guarantees no other
+        // references to items exist.
+        Some(unsafe { item.as_mut() })
+    }
+
+    /// Replaces the item at the specified position with `value`, returning
+    /// the old item, or [`None`] if the position is at the end of the arena
+    /// (in which case `value` is dropped).
+    ///
+    /// # Panics
+    ///
+    /// May panic if `position` does not refer to a position in this arena.
+    pub fn replace_at(&mut self, position: &Position, value: T) -> Option<T>
+    where
+        Options: ArenaOptions<T, Pinned = Bool<false>>,
+    {
+        let mut item = self.0.get_mut().ptr_at(position)?;
+        // SAFETY: `ManuallyDropArena::ptr_at` returns initialized, properly
+        // aligned pointers, and this type's design guarantees no other
+        // references to items exist.
+        Some(mem::replace(unsafe { item.as_mut() }, value))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, Options> Arena<T, Options>
+where
+    T: Clone + Eq + Hash,
+    Options: ArenaOptions<T, Mutable = Bool<false>>,
+{
+    /// Creates a new arena containing clones of the unique items in this
+    /// arena, along with a map from each unique item to its index in the new
+    /// arena.
+    ///
+    /// Items in the new arena are ordered by their first occurrence in this
+    /// arena.
+    ///
+    /// This method requires the crate feature `std`.
+    pub fn dedup_into(&self) -> (Self, HashMap<&T, usize>) {
+        let arena = Self::new();
+        let mut indices = HashMap::new();
+        for item in self {
+            indices.entry(item).or_insert_with(|| {
+                arena.alloc_shared(item.clone());
+                arena.len() - 1
+            });
+        }
+        (arena, indices)
+    }
 }
 
 // SAFETY: `Arena` owns its items and provides access using standard borrow
@@ -234,6 +1272,251 @@ where
     }
 }
 
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn index_out_of_bounds(index: usize, len: usize) -> ! {
+    panic!(
+        "index out of bounds: the len is {} but the index is {}",
+        len, index
+    );
+}
+
+impl<T, Options> Clone for Arena<T, Options>
+where
+    T: Clone,
+    Options: ArenaOptions<T, Mutable = Bool<false>>,
+{
+    /// Creates a new arena containing clones of the items in this arena.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    fn clone(&self) -> Self {
+        let arena = Self::new();
+        arena.reserve(self.len());
+        for item in self {
+            arena.alloc_shared(item.clone());
+        }
+        arena
+    }
+
+    /// Replaces the items in this arena with clones of the items in `source`.
+    ///
+    /// The existing items are dropped, but this arena's chunks are kept and
+    /// reused, so new chunks are allocated only if `source` has more items
+    /// than this arena has space for. All existing [`Position`]s for this
+    /// arena are invalidated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for `source` are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    fn clone_from(&mut self, source: &Self) {
+        // SAFETY: This type's design guarantees no references to items exist.
+        unsafe {
+            self.0.get_mut().clear();
+        }
+        self.reserve(source.len());
+        for item in source {
+            self.alloc_shared(item.clone());
+        }
+    }
+}
+
+impl<T, Options> Hash for Arena<T, Options>
+where
+    T: Hash,
+    Options: ArenaOptions<T, Mutable = Bool<false>>,
+{
+    /// Hashes the number of items followed by each item, in order, like a
+    /// slice. The arena's chunk structure isn't hashed, so arenas with the
+    /// same items hash equally regardless of [`Options::ChunkSize`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for this arena are alive.
+    ///
+    /// [`Options::ChunkSize`]: ArenaOptions::ChunkSize
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for item in self {
+            item.hash(state);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, Options> Serialize for Arena<T, Options>
+where
+    T: Serialize,
+    Options: ArenaOptions<T, Mutable = Bool<false>>,
+{
+    /// Serializes the items, in order, as a sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Options::TrackBorrows`] is true and any [`ArenaRefMut`]
+    /// guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, Options> Deserialize<'de> for Arena<T, Options>
+where
+    T: Deserialize<'de>,
+    Options: ArenaOptions<T>,
+{
+    /// Deserializes a sequence, allocating each item in order.
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct Visitor<T, Options>(PhantomData<fn() -> (T, Options)>);
+
+        impl<'de, T, Options> de::Visitor<'de> for Visitor<T, Options>
+        where
+            T: Deserialize<'de>,
+            Options: ArenaOptions<T>,
+        {
+            type Value = Arena<T, Options>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut arena = Arena::new();
+                while let Some(item) = seq.next_element()? {
+                    arena.0.get_mut().alloc_ptr(item);
+                }
+                Ok(arena)
+            }
+        }
+
+        deserializer.deserialize_seq(Visitor(PhantomData))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Arena<T> {
+    /// Creates a new arena containing the items in `array`, in order.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    fn from(array: [T; N]) -> Self {
+        let inner = ManuallyDropArena::from(array);
+        // SAFETY: `inner` was just created, so no references to its items
+        // exist.
+        unsafe { Self::from_manually_drop(inner) }
+    }
+}
+
+impl<T, Options: ArenaOptions<T>> FromIterator<T> for Arena<T, Options> {
+    /// Creates a new arena containing the items in `iter`, in order.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut arena = Self::new();
+        arena.0.get_mut().collect_from(iter);
+        arena
+    }
+}
+
+/// Lets `&Arena` be used wherever an [`Extend`] collection is expected, like
+/// [`Arena::alloc`], which takes `&self`. Arenas with immutable items can be
+/// extended with [`Arena::alloc_extend`].
+impl<T, Options> Extend<T> for &Arena<T, Options>
+where
+    Options: ArenaOptions<T, Mutable = Bool<true>>,
+{
+    /// Allocates every item in `iter`. See [`Arena::alloc_extend`].
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.alloc_extend(iter);
+    }
+}
+
+impl<T, Options> Index<usize> for Arena<T, Options>
+where
+    Options: ArenaOptions<T, Mutable = Bool<false>>,
+{
+    type Output = T;
+
+    /// Returns a reference to the item at index `index`.
+    ///
+    /// This method runs in O(`index / CHUNK_SIZE`) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, or if [`Options::TrackBorrows`] is
+    /// true and any [`ArenaRefMut`] guards for this arena are alive.
+    ///
+    /// [`Options::TrackBorrows`]: ArenaOptions::TrackBorrows
+    #[track_caller]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| {
+            index_out_of_bounds(index, self.len());
+        })
+    }
+}
+
+/// Note that because [`IndexMut`] requires [`Index`], this trait is
+/// implemented only when [`Options::Mutable`] is false. [`Index`] can't be
+/// implemented for other arenas: it takes `&self`, and so does
+/// [`Arena::alloc`], which returns mutable references that a shared reference
+/// from [`Index::index`] could alias. For those arenas, use
+/// [`Arena::get_mut`]:
+///
+/// ```compile_fail
+/// # use fixed_typed_arena::Arena;
+/// let mut arena = Arena::<u8>::new();
+/// arena.alloc(1);
+/// arena[0] += 1; // Error: `Index` is not implemented
+/// ```
+///
+/// [`Options::Mutable`]: ArenaOptions::Mutable
+impl<T, Options> IndexMut<usize> for Arena<T, Options>
+where
+    Options: ArenaOptions<T, Mutable = Bool<false>, Pinned = Bool<false>>,
+{
+    /// Returns a mutable reference to the item at index `index`.
+    ///
+    /// This method runs in O(`index / CHUNK_SIZE`) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| {
+            index_out_of_bounds(index, len);
+        })
+    }
+}
+
 impl<'a, T, Options> IntoIterator for &'a Arena<T, Options>
 where
     Options: ArenaOptions<T, Mutable = Bool<false>>,
@@ -248,7 +1531,7 @@ where
 
 impl<'a, T, Options> IntoIterator for &'a mut Arena<T, Options>
 where
-    Options: ArenaOptions<T>,
+    Options: ArenaOptions<T, Pinned = Bool<false>>,
 {
     type IntoIter = IterMut<'a, T, Options>;
     type Item = &'a mut T;
@@ -258,18 +1541,15 @@ where
     }
 }
 
-impl<T, Options: ArenaOptions<T>> IntoIterator for Arena<T, Options> {
+impl<T, Options> IntoIterator for Arena<T, Options>
+where
+    Options: ArenaOptions<T, Pinned = Bool<false>>,
+{
     type IntoIter = IntoIter<T, Options>;
     type Item = T;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut this = ManuallyDrop::new(self);
-
-        // SAFETY: This `ManuallyDrop` won't be used again because we moved
-        // `self` into a `ManuallyDrop` (so its destructor won't run).
-        let inner = unsafe { ManuallyDrop::take(&mut this.0) };
-
         // SAFETY: This type's design guarantees no references to items exist.
-        unsafe { inner.into_inner().into_iter_unchecked() }
+        unsafe { self.into_manually_drop().into_iter_unchecked() }
     }
 }
@@ -0,0 +1,169 @@
+/*
+ * Copyright (C) 2022 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of fixed-typed-arena.
+ *
+ * fixed-typed-arena is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * fixed-typed-arena is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with fixed-typed-arena. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::chunk::item_stride;
+use crate::iter::Position;
+use crate::manually_drop::ManuallyDropArena;
+use crate::ArenaOptions;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use integral_constant::Bool;
+
+/// A cursor over the items in an [`Arena`](super::Arena) that can move
+/// forward and backward and mutate the item it points to.
+///
+/// The cursor points either to an item or to a "ghost" non-element between
+/// the last and first items. Moving forward from the last item or backward
+/// from the first item moves the cursor to the ghost non-element, and moving
+/// from the ghost non-element moves the cursor to the first or last item.
+///
+/// See [`Arena::cursor_mut`](super::Arena::cursor_mut).
+pub struct CursorMut<'a, T, Options: ArenaOptions<T>> {
+    arena: &'a ManuallyDropArena<T, Options>,
+    /// A pointer to the first item in each chunk, along with the number of
+    /// initialized items in the chunk. Every chunk is non-empty.
+    chunks: Vec<(*mut T, usize)>,
+    /// The index of the current chunk in `chunks`, or `chunks.len()` if the
+    /// cursor is at the ghost non-element.
+    chunk: usize,
+    /// The index of the current item in the current chunk.
+    index: usize,
+    phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, Options: ArenaOptions<T>> CursorMut<'a, T, Options> {
+    /// # Safety
+    ///
+    /// No references to items (or parts of items) in `arena` or instances of
+    /// [`Iter`] or [`IterMut`] for `arena` may exist for `'a`.
+    ///
+    /// [`Iter`]: crate::iter::Iter
+    /// [`IterMut`]: crate::iter::IterMut
+    pub(super) unsafe fn new(
+        arena: &'a ManuallyDropArena<T, Options>,
+    ) -> Self {
+        Self {
+            arena,
+            chunks: arena.chunk_starts().collect(),
+            chunk: 0,
+            index: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the index of the current item in the arena, or [`None`] if
+    /// the cursor is at the ghost non-element.
+    pub fn index(&self) -> Option<usize> {
+        if self.chunk >= self.chunks.len() {
+            return None;
+        }
+        let before: usize =
+            self.chunks[..self.chunk].iter().map(|&(_, len)| len).sum();
+        Some(before + self.index)
+    }
+
+    /// Returns a reference to the current item, or [`None`] if the cursor is
+    /// at the ghost non-element.
+    pub fn current(&mut self) -> Option<&mut T> {
+        let (start, _) = *self.chunks.get(self.chunk)?;
+        // SAFETY: `start` points to the initialized items in the current
+        // chunk, and `self.index` is always less than the number of items. The
+        // returned reference borrows `self`, so no other references to
+        // the item can be created through this cursor while it exists,
+        // and `Self::new` guarantees no others exist.
+        Some(unsafe { &mut *start.add(self.index) })
+    }
+
+    /// Moves the cursor to the next item. If the cursor is at the ghost
+    /// non-element, this moves it to the first item; if the cursor is at the
+    /// last item, this moves it to the ghost non-element.
+    pub fn move_next(&mut self) {
+        let len = if let Some((_, len)) = self.chunks.get(self.chunk) {
+            *len
+        } else {
+            self.chunk = 0;
+            self.index = 0;
+            return;
+        };
+        self.index += 1;
+        if self.index == len {
+            self.chunk += 1;
+            self.index = 0;
+        }
+    }
+
+    /// Moves the cursor to the previous item. If the cursor is at the ghost
+    /// non-element, this moves it to the last item; if the cursor is at the
+    /// first item, this moves it to the ghost non-element.
+    pub fn move_prev(&mut self) {
+        if self.index > 0 {
+            self.index -= 1;
+        } else if self.chunk == 0 {
+            self.chunk = self.chunks.len();
+        } else {
+            self.chunk -= 1;
+            self.index = self.chunks[self.chunk].1 - 1;
+        }
+    }
+}
+
+impl<T, Options> CursorMut<'_, T, Options>
+where
+    Options: ArenaOptions<T, SupportsPositions = Bool<true>>,
+{
+    /// Returns a [`Position`] referring to the current item, or to the end of
+    /// the arena if the cursor is at the ghost non-element.
+    ///
+    /// This method runs in O(`index / CHUNK_SIZE`) time.
+    pub fn position(&self) -> Position {
+        let index = self.index().unwrap_or_else(|| self.arena.len());
+        self.arena.position_at_index(index).unwrap_or_else(|| {
+            unreachable!(
+                "`index` should not be greater than the arena length"
+            );
+        })
+    }
+
+    /// Moves the cursor to the item at the specified position, or to the
+    /// ghost non-element if the position is at the end of the arena.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `position` does not refer to a position in this arena.
+    pub fn seek(&mut self, position: &Position) {
+        let item = if let Some(item) = self.arena.ptr_at(position) {
+            item.as_ptr() as usize
+        } else {
+            self.chunk = self.chunks.len();
+            self.index = 0;
+            return;
+        };
+
+        let size = item_stride::<T>();
+        for (i, &(start, len)) in self.chunks.iter().enumerate() {
+            let start = start as usize;
+            if (start..start + len * size).contains(&item) {
+                self.chunk = i;
+                self.index = (item - start) / size;
+                return;
+            }
+        }
+        unreachable!("`position` should refer to an item in the arena");
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * Copyright (C) 2022 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of fixed-typed-arena.
+ *
+ * fixed-typed-arena is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * fixed-typed-arena is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with fixed-typed-arena. If not, see <https://www.gnu.org/licenses/>.
+ */
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// An index-based handle to an item in an arena.
+///
+/// Unlike a reference, a handle doesn't borrow the arena, so handles can be
+/// stored in items in the same arena without requiring the
+/// `dropck_eyepatch` feature. Dereferencing a handle is a bounds-checked
+/// lookup that runs in O(`index / CHUNK_SIZE`) time.
+///
+/// See [`Arena::alloc_handle`](super::Arena::alloc_handle).
+pub struct Ref<T> {
+    index: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Ref<T> {
+    pub(super) fn new(index: usize) -> Self {
+        Self {
+            index,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the index of the item this handle refers to.
+    pub fn index(self) -> usize {
+        self.index
+    }
+}
+
+impl<T> Clone for Ref<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Ref<T> {}
+
+impl<T> PartialEq for Ref<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T> Eq for Ref<T> {}
+
+impl<T> Hash for Ref<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Ref<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ref").field(&self.index).finish()
+    }
+}
@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2022 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of fixed-typed-arena.
+ *
+ * fixed-typed-arena is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * fixed-typed-arena is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with fixed-typed-arena. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use core::cell::Cell;
+use core::ops::{Deref, DerefMut};
+
+/// A guard that provides mutable access to an item in an arena whose
+/// [`Mutable`] option is false and [`TrackBorrows`] option is true.
+///
+/// While any guards for an arena are alive, methods that provide immutable
+/// access to existing items in the arena panic.
+///
+/// See [`Arena::alloc_tracked`](super::Arena::alloc_tracked).
+///
+/// [`Mutable`]: crate::ArenaOptions::Mutable
+/// [`TrackBorrows`]: crate::ArenaOptions::TrackBorrows
+pub struct ArenaRefMut<'a, T> {
+    item: &'a mut T,
+    /// The number of live guards for the arena.
+    borrows: &'a Cell<usize>,
+}
+
+impl<'a, T> ArenaRefMut<'a, T> {
+    /// `borrows` must have already been incremented to account for this
+    /// guard.
+    pub(super) fn new(item: &'a mut T, borrows: &'a Cell<usize>) -> Self {
+        Self {
+            item,
+            borrows,
+        }
+    }
+}
+
+impl<T> Deref for ArenaRefMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.item
+    }
+}
+
+impl<T> DerefMut for ArenaRefMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.item
+    }
+}
+
+impl<T> Drop for ArenaRefMut<'_, T> {
+    fn drop(&mut self) {
+        self.borrows.set(self.borrows.get() - 1);
+    }
+}
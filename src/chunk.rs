@@ -17,54 +17,83 @@
  * along with fixed-typed-arena. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use super::options::{AllocatorPriv, Global};
 use alloc::alloc::Layout;
-use alloc::boxed::Box;
 use core::marker::PhantomData;
 use core::mem::{self, MaybeUninit};
-use core::ptr::{addr_of_mut, NonNull};
+use core::ptr::{self, addr_of_mut, NonNull};
 
-struct Chunk<T, Array> {
+struct Chunk<T, Array, A> {
     items: MaybeUninit<Array>,
-    next: Option<ChunkRef<T, Array>>,
-    phantom: PhantomData<T>,
+    next: Option<ChunkRef<T, Array, A>>,
+    /// The number of slots at the start of the chunk that can hold items.
+    /// See [`ChunkRef::usable`].
+    usable: usize,
+    phantom: PhantomData<(T, fn() -> A)>,
 }
 
 // Invariant: `self.0` always points to a valid, initialized, properly aligned
 // `Chunk`.
 #[repr(transparent)]
-pub struct ChunkRef<T, Array>(NonNull<Chunk<T, Array>>);
+pub struct ChunkRef<T, Array, A = Global>(NonNull<Chunk<T, Array, A>>);
 
-impl<T, Array> PartialEq for ChunkRef<T, Array> {
+impl<T, Array, A> PartialEq for ChunkRef<T, Array, A> {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
     }
 }
 
-impl<T, Array> Eq for ChunkRef<T, Array> {}
+impl<T, Array, A> Eq for ChunkRef<T, Array, A> {}
 
-impl<T, Array> Clone for ChunkRef<T, Array> {
+impl<T, Array, A> Clone for ChunkRef<T, Array, A> {
     fn clone(&self) -> Self {
         Self(self.0)
     }
 }
 
-impl<T, Array> ChunkRef<T, Array> {
-    pub const CAPACITY: usize = mem::size_of::<Array>() / mem::size_of::<T>();
-    pub const LAYOUT: Layout = Layout::new::<Chunk<T, Array>>();
+/// Returns the distance in bytes between pointers to consecutive items of
+/// type `T` in a chunk.
+///
+/// Zero-sized items don't occupy any memory, so [`ChunkRef::get`] gives each
+/// index its own dangling address, spaced [`mem::align_of::<T>()`] bytes
+/// apart.
+pub const fn item_stride<T>() -> usize {
+    if mem::size_of::<T>() == 0 {
+        mem::align_of::<T>()
+    } else {
+        mem::size_of::<T>()
+    }
+}
+
+impl<T, Array, A: AllocatorPriv> ChunkRef<T, Array, A> {
+    /// The number of items in a chunk. For zero-sized types, this is as large
+    /// as possible (regardless of the configured chunk size), so only one
+    /// chunk is ever needed.
+    pub const CAPACITY: usize = if mem::size_of::<T>() == 0 {
+        // Leaves room for the address of index `CAPACITY` (see `get_uninit`).
+        usize::MAX / mem::align_of::<T>() - 1
+    } else {
+        mem::size_of::<Array>() / mem::size_of::<T>()
+    };
+
+    pub const LAYOUT: Layout = Layout::new::<Chunk<T, Array, A>>();
 
     pub fn new(prev: Option<Self>) -> Option<Self> {
         assert!(mem::align_of::<Array>() >= mem::align_of::<T>());
-        assert!(mem::size_of::<Array>() % mem::size_of::<T>() == 0);
+        assert!(
+            mem::size_of::<T>() == 0
+                || mem::size_of::<Array>() % mem::size_of::<T>() == 0
+        );
         assert!(Self::LAYOUT.size() > 0);
 
-        // SAFETY: We ensured `Self::LAYOUT` has non-zero size above.
-        let ptr: NonNull<Chunk<T, Array>> =
-            NonNull::new(unsafe { alloc::alloc::alloc(Self::LAYOUT) })?.cast();
+        let ptr: NonNull<Chunk<T, Array, A>> =
+            A::allocate(Self::LAYOUT)?.cast();
 
-        // SAFETY: `alloc::alloc::alloc` returns valid, properly aligned
-        // memory.
+        // SAFETY: `AllocatorPriv::allocate` returns valid memory that fits
+        // `Self::LAYOUT`.
         unsafe {
             addr_of_mut!((*ptr.as_ptr()).next).write(None);
+            addr_of_mut!((*ptr.as_ptr()).usable).write(Self::CAPACITY);
         }
 
         let chunk = Self(ptr);
@@ -91,13 +120,31 @@ impl<T, Array> ChunkRef<T, Array> {
         unsafe { &(*self.0.as_ptr()).next }.clone()
     }
 
-    fn set_next(&mut self, next: Option<Self>) {
+    pub fn set_next(&mut self, next: Option<Self>) {
         // SAFETY: `self.0` is always initialized and properly aligned.
         unsafe {
             (*self.0.as_ptr()).next = next;
         }
     }
 
+    /// Returns the number of slots at the start of the chunk that can hold
+    /// items. This is [`Self::CAPACITY`] unless the chunk was left before it
+    /// was full, in which case the slots after the first `self.usable()` are
+    /// never used.
+    pub fn usable(&self) -> usize {
+        // SAFETY: `self.0` is always initialized and properly aligned.
+        unsafe { (*self.0.as_ptr()).usable }
+    }
+
+    /// Sets the number of slots that can hold items (see [`Self::usable`]).
+    pub fn set_usable(&mut self, usable: usize) {
+        debug_assert!(usable <= Self::CAPACITY);
+        // SAFETY: `self.0` is always initialized and properly aligned.
+        unsafe {
+            (*self.0.as_ptr()).usable = usable;
+        }
+    }
+
     /// Frees the memory in this chunk.
     ///
     /// # Safety
@@ -107,9 +154,11 @@ impl<T, Array> ChunkRef<T, Array> {
     /// never be accessed, except for being dropped. This is trivially true if
     /// no such clones exist.
     pub unsafe fn dealloc(self) {
-        // SAFETY: `self.0` was allocated by `alloc::alloc::alloc` and can thus
-        // be turned into a `Box`.
-        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+        // SAFETY: `self.0` was allocated by `A::allocate` with
+        // `Self::LAYOUT`. `Chunk` has no fields that need to be dropped.
+        unsafe {
+            A::deallocate(self.0.cast(), Self::LAYOUT);
+        }
     }
 
     /// Returns a pointer to the item at index `i`. If `i` is less than
@@ -125,6 +174,16 @@ impl<T, Array> ChunkRef<T, Array> {
         unsafe { self.get_uninit(i).cast() }
     }
 
+    /// Like [`Self::get`], but returns [`None`] if `i` is greater than
+    /// [`Self::CAPACITY`].
+    pub fn try_get(&self, i: usize) -> Option<NonNull<T>> {
+        if i > Self::CAPACITY {
+            return None;
+        }
+        // SAFETY: We just checked that `i` is in bounds.
+        Some(unsafe { self.get(i) })
+    }
+
     /// Returns a pointer to the item at index `i`. If `i` is less than
     /// [`Self::CAPACITY`], the pointer is guaranteed to be valid and
     /// properly aligned.
@@ -134,6 +193,15 @@ impl<T, Array> ChunkRef<T, Array> {
     /// `i` must be less than or equal to [`Self::CAPACITY`].
     unsafe fn get_uninit(&self, i: usize) -> NonNull<MaybeUninit<T>> {
         debug_assert!(i <= Self::CAPACITY);
+        if mem::size_of::<T>() == 0 {
+            // Every non-null, properly aligned pointer is valid for
+            // zero-sized types. Distinct addresses let iterators tell items
+            // apart by address, as they do for other types.
+            let addr = (i + 1) * mem::align_of::<T>();
+            // SAFETY: `i` is less than or equal to `Self::CAPACITY`, so this
+            // doesn't overflow, and `addr` is at least 1.
+            return unsafe { NonNull::new_unchecked(addr as *mut _) };
+        }
 
         // SAFETY: `self.0` is always valid and properly aligned. We use
         // `addr_of_mut` here to avoid creating a reference to the entire
@@ -145,31 +213,79 @@ impl<T, Array> ChunkRef<T, Array> {
         unsafe { NonNull::new_unchecked(ptr.cast::<MaybeUninit<T>>().add(i)) }
     }
 
+    /// Drops the first `len` items in the chunk. If an item's destructor
+    /// panics, the remaining items are still dropped.
+    ///
+    /// With the crate feature `debug-poison`, debug builds then fill the
+    /// dropped items' memory with [`POISON`] bytes.
+    ///
     /// # Safety
     ///
-    /// * The item at index `i` must be initialized. Note that this method will
-    ///   make it uninitialized.
-    /// * It must be safe to drop the item at index `i`.
-    pub unsafe fn drop_item(&mut self, i: usize) {
-        // SAFETY: Caller guarantees that item `i` is initialized (which
-        // requires `i` to be in bounds) and safe to drop.
+    /// * `len` must be less than or equal to [`Self::CAPACITY`].
+    /// * The first `len` items must be initialized. Note that this method will
+    ///   make them uninitialized.
+    /// * It must be safe to drop the first `len` items.
+    pub unsafe fn drop_items(&mut self, len: usize) {
+        debug_assert!(len <= Self::CAPACITY);
+        // SAFETY: 0 is always less than or equal to the chunk capacity.
+        let start = unsafe { self.get(0) }.as_ptr();
+        #[cfg(all(feature = "debug-poison", debug_assertions))]
+        let _poison = PoisonGuard(start, len);
+        if !mem::needs_drop::<T>() {
+            return;
+        }
+        // SAFETY: Caller guarantees that the first `len` items are
+        // initialized and safe to drop. Dropping a slice continues to drop
+        // the remaining elements if a destructor panics.
         unsafe {
-            self.get(i).as_ptr().drop_in_place();
+            ptr::slice_from_raw_parts_mut(start, len).drop_in_place();
         }
     }
 
+    /// Drops the first `len` items in the chunk and then frees its memory.
+    /// The memory is freed even if an item's destructor panics.
+    ///
     /// # Safety
     ///
-    /// * All items in the chunk must be initialized. Note that this method
-    ///   will make them uninitialized.
-    /// * It must be safe to drop all items.
-    pub unsafe fn drop_all(&mut self) {
-        for i in 0..Self::CAPACITY {
-            // SAFETY: Caller guarantees that all items are initialized and
-            // safe to drop.
-            unsafe {
-                self.drop_item(i);
+    /// Same requirements as [`Self::drop_items`] and [`Self::dealloc`].
+    pub unsafe fn drop_and_dealloc(self, len: usize) {
+        struct Guard<T, Array, A: AllocatorPriv>(ChunkRef<T, Array, A>);
+
+        impl<T, Array, A: AllocatorPriv> Drop for Guard<T, Array, A> {
+            fn drop(&mut self) {
+                // SAFETY: Checked by caller of `drop_and_dealloc`.
+                unsafe {
+                    self.0.clone().dealloc();
+                }
             }
         }
+
+        let mut guard = Guard(self);
+        // SAFETY: Checked by caller.
+        unsafe {
+            guard.0.drop_items(len);
+        }
+    }
+}
+
+/// The byte that dropped items are overwritten with when the crate feature
+/// `debug-poison` is enabled in debug builds.
+#[cfg(all(feature = "debug-poison", debug_assertions))]
+pub const POISON: u8 = 0xdd;
+
+/// Fills `self.1` items starting at `self.0` with [`POISON`] bytes when
+/// dropped, even if an item's destructor panicked.
+#[cfg(all(feature = "debug-poison", debug_assertions))]
+struct PoisonGuard<T>(*mut T, usize);
+
+#[cfg(all(feature = "debug-poison", debug_assertions))]
+impl<T> Drop for PoisonGuard<T> {
+    fn drop(&mut self) {
+        // SAFETY: The creator of this guard ensures that the items are
+        // within a single chunk and will have been dropped by the time the
+        // guard is, so nothing will read them as values of type `T` again.
+        unsafe {
+            ptr::write_bytes(self.0, POISON, self.1);
+        }
     }
 }
@@ -40,10 +40,22 @@
 //! are performed in non-amortized constant time.
 //!
 //! This crate depends only on [`core`] and [`alloc`], so it can be used in
-//! `no_std` environments that support [`alloc`].
+//! `no_std` environments that support [`alloc`]. Some additional methods that
+//! require [`std`] are available with the crate feature `std`. Cumulative
+//! allocation statistics can be collected with the crate feature `stats`.
+//! With the crate feature `debug-poison`, debug builds overwrite the memory of
+//! dropped items with `0xdd` bytes to make use-after-free bugs easier to spot.
+//! With the crate feature `allocator_api`, chunks can be allocated with a
+//! custom zero-sized allocator from [allocator-api2] (see
+//! [`ArenaOptions::Allocator`]).
+//! With the crate feature `serde`, arenas can be serialized and deserialized
+//! with [serde] as sequences of items.
 //!
 //! [`core`]: https://doc.rust-lang.org/core/
 //! [`alloc`]: https://doc.rust-lang.org/alloc/
+//! [`std`]: https://doc.rust-lang.org/std/
+//! [allocator-api2]: https://docs.rs/allocator-api2
+//! [serde]: https://docs.rs/serde
 //!
 //! Example
 //! -------
@@ -82,6 +94,9 @@
 //! the tradeoff that it will leak memory unless the unsafe [`drop`] method is
 //! called.
 //!
+//! For targets without a global allocator, [`StaticArena`] stores a fixed
+//! number of items inline and never allocates memory.
+//!
 //! Iteration
 //! ---------
 //!
@@ -94,8 +109,11 @@
 //! [`Arena`]: arena::Arena
 //! [`ManuallyDropArena`]: manually_drop::ManuallyDropArena
 //! [`drop`]: manually_drop::ManuallyDropArena::drop
+//! [`StaticArena`]: static_arena::StaticArena
 
 extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
 
 mod chunk;
 pub mod options;
@@ -104,7 +122,9 @@ mod tests;
 
 pub mod arena;
 pub mod manually_drop;
-pub use options::{ArenaOptions, Options};
+pub mod static_arena;
+pub mod uninit;
+pub use options::{chunk_size_for, ArenaOptions, Options};
 
 /// Arena iterators.
 pub mod iter {
@@ -118,12 +138,18 @@ pub type Arena<
     const CHUNK_SIZE: usize = 16,
     const SUPPORTS_POSITIONS: bool = false,
     const MUTABLE: bool = true,
+    const MAX_CHUNKS: usize = { usize::MAX },
+    const TRACK_BORROWS: bool = false,
+    const PINNED: bool = false,
 > = arena::Arena<
     T,
     Options<
         CHUNK_SIZE,
         SUPPORTS_POSITIONS,
         MUTABLE,
+        MAX_CHUNKS,
+        TRACK_BORROWS,
+        PINNED,
     >,
 >;
 
@@ -134,11 +160,17 @@ pub type ManuallyDropArena<
     const CHUNK_SIZE: usize = 16,
     const SUPPORTS_POSITIONS: bool = false,
     const MUTABLE: bool = true,
+    const MAX_CHUNKS: usize = { usize::MAX },
+    const TRACK_BORROWS: bool = false,
+    const PINNED: bool = false,
 > = manually_drop::ManuallyDropArena<
     T,
     Options<
         CHUNK_SIZE,
         SUPPORTS_POSITIONS,
         MUTABLE,
+        MAX_CHUNKS,
+        TRACK_BORROWS,
+        PINNED,
     >,
 >;
@@ -19,21 +19,33 @@
 
 //! An arena that returns references with arbitrary lifetimes.
 
+use super::arena::Arena;
 use super::chunk::ChunkRef;
-use super::options::{ChunkSizePriv, SupportsPositionsPriv};
+use super::options::SupportsPositionsPriv;
+use super::options::{AllocatorPriv, ChunkSizePriv, MaxChunksPriv};
 use super::ArenaOptions;
 use alloc::alloc::handle_alloc_error;
 use alloc::boxed::Box;
 use alloc::sync::Arc;
-use core::fmt::{Debug, Display};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
 use core::hint::unreachable_unchecked;
+use core::iter::{successors, FromIterator};
 use core::marker::PhantomData;
 use core::mem;
+use core::ops::Range;
 use core::ptr::{self, NonNull};
 use integral_constant::Bool;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer};
 
 pub(crate) mod iter;
+#[cfg(feature = "stats")]
+mod stats;
 use iter::{IntoIter, Iter, IterMut, IterPtr, Position};
+#[cfg(feature = "stats")]
+pub use stats::Stats;
 
 type Array<T, Options> =
     <<Options as ArenaOptions<T>>::ChunkSize as ChunkSizePriv<T>>::Array;
@@ -41,11 +53,103 @@ type SupportsPositions<T, Options> =
     <Options as ArenaOptions<T>>::SupportsPositions;
 type ArenaRc<T, Options> =
     <SupportsPositions<T, Options> as SupportsPositionsPriv>::Rc;
-type ArenaChunk<T, Options> = ChunkRef<T, Array<T, Options>>;
+type ArenaChunk<T, Options> =
+    ChunkRef<T, Array<T, Options>, <Options as ArenaOptions<T>>::Allocator>;
+type MaxChunks<T, Options> = <Options as ArenaOptions<T>>::MaxChunks;
+
+/// The reason an allocation failed.
+#[derive(Clone, Copy)]
+enum AllocFailure {
+    /// The global allocator could not allocate a new chunk.
+    Oom,
+    /// The arena already has [`ArenaOptions::MaxChunks`] chunks.
+    ChunkLimit,
+    /// The arena already has the maximum number of items ([`usize::MAX`], or
+    /// less for zero-sized types).
+    LenOverflow,
+}
+
+impl From<AllocFailure> for AllocFailureKind {
+    fn from(failure: AllocFailure) -> Self {
+        match failure {
+            AllocFailure::Oom => Self::Oom,
+            AllocFailure::ChunkLimit | AllocFailure::LenOverflow => {
+                Self::CapacityCapReached
+            }
+        }
+    }
+}
+
+/// The reason an allocation failed, as returned by
+/// [`ManuallyDropArena::try_alloc_detailed`] and
+/// [`Arena::try_alloc_detailed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocFailureKind {
+    /// The global allocator could not allocate a new chunk.
+    Oom,
+    /// The arena cannot hold any more items, either because it already has
+    /// [`ArenaOptions::MaxChunks`] chunks or because its length would
+    /// overflow.
+    CapacityCapReached,
+}
+
+impl fmt::Display for AllocFailureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Oom => "memory allocation failed",
+            Self::CapacityCapReached => "arena cannot hold any more items",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AllocFailureKind {}
+
+/// A handle to a [`ManuallyDropArena`] that is being traversed by
+/// [`ManuallyDropArena::drain_worklist`]. New items can be allocated through
+/// the handle, but existing items can't be changed or removed.
+pub struct WorklistArena<'a, T, Options: ArenaOptions<T>>(
+    &'a mut ManuallyDropArena<T, Options>,
+);
+
+impl<T, Options: ArenaOptions<T>> WorklistArena<'_, T, Options> {
+    /// Allocates a new item in the arena. See
+    /// [`ManuallyDropArena::alloc_shared`].
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn alloc_shared<'b>(&mut self, value: T) -> &'b T
+    where
+        Options: 'b,
+    {
+        self.0.alloc_shared(value)
+    }
+
+    /// Like [`Self::alloc_shared`], but returns [`None`] if memory allocation
+    /// fails.
+    pub fn try_alloc_shared<'b>(&mut self, value: T) -> Option<&'b T>
+    where
+        Options: 'b,
+    {
+        self.0.try_alloc_shared(value)
+    }
+
+    /// Returns the number of items in the arena.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the arena is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
 
 /// Checks whether `old` and `new` point to the same allocation (see
 /// [`Arc::ptr_eq`]), but allows `old` to be [`None`], even if `new` is
 /// [`Some`].
+#[allow(clippy::ref_option)]
 fn valid_rc_update<T>(old: &Option<Arc<T>>, new: &Option<Arc<T>>) -> bool {
     match (old, new) {
         (Some(old), Some(new)) => Arc::ptr_eq(old, new),
@@ -54,13 +158,96 @@ fn valid_rc_update<T>(old: &Option<Arc<T>>, new: &Option<Arc<T>>) -> bool {
     }
 }
 
+/// Drops the first `len` items in the list of chunks starting at `head` and,
+/// if `DEALLOC` is true, frees every chunk in the list. Otherwise, every slot
+/// in the chunks that contained items is made usable again (see
+/// [`ChunkRef::usable`]). If an item's destructor panics, the remaining items
+/// are still dropped and (if `DEALLOC` is true) all chunks are still freed.
+///
+/// # Safety
+///
+/// * If `DEALLOC` is true, no other [`ChunkRef`]s may refer to any chunk in
+///   the list.
+/// * The first `len` items in the list must be initialized and safe to drop,
+///   and no other code may access them during or after this call.
+unsafe fn drop_chunks<T, Array, A: AllocatorPriv, const DEALLOC: bool>(
+    head: ChunkRef<T, Array, A>,
+    len: usize,
+) {
+    // Invariant: `chunk` and `len` always satisfy the requirements of
+    // `drop_chunks`.
+    struct Guard<T, Array, A: AllocatorPriv, const DEALLOC: bool> {
+        chunk: Option<ChunkRef<T, Array, A>>,
+        len: usize,
+    }
+
+    impl<T, Array, A: AllocatorPriv, const DEALLOC: bool>
+        Guard<T, Array, A, DEALLOC>
+    {
+        /// Drops the items in the first chunk and, if `DEALLOC` is true,
+        /// frees it, or otherwise resets its number of usable slots. Returns
+        /// whether there was a chunk to process.
+        fn pop(&mut self) -> bool {
+            let mut chunk = if let Some(chunk) = self.chunk.take() {
+                chunk
+            } else {
+                return false;
+            };
+
+            let len = self.len.min(chunk.usable());
+            self.len -= len;
+            if DEALLOC || self.len > 0 {
+                self.chunk = chunk.next();
+            }
+            if !DEALLOC {
+                chunk.set_usable(ChunkRef::<T, Array, A>::CAPACITY);
+            }
+
+            // SAFETY: This type's invariants guarantee that the first `len`
+            // items in `chunk` are initialized and safe to drop and, if
+            // `DEALLOC` is true, that no other `ChunkRef`s refer to `chunk`.
+            // `chunk` has been removed from the list, so it won't be accessed
+            // again.
+            unsafe {
+                if DEALLOC {
+                    chunk.drop_and_dealloc(len);
+                } else {
+                    chunk.drop_items(len);
+                }
+            }
+            true
+        }
+    }
+
+    impl<T, Array, A: AllocatorPriv, const DEALLOC: bool> Drop
+        for Guard<T, Array, A, DEALLOC>
+    {
+        fn drop(&mut self) {
+            // Only runs if an item's destructor panicked.
+            while self.pop() {}
+        }
+    }
+
+    let mut guard = Guard::<_, _, _, DEALLOC> {
+        chunk: Some(head),
+        len,
+    };
+    while guard.pop() {}
+}
+
 // Invariants:
 //
-// * Every chunk except for `tail` must be full (all items initialized).
-// * If `tail_len` is less than `Self::CHUNK_SIZE`, `tail` is `Some`.
-// * If `tail` is `Some`, it contains at least one item (`tail_len > 0`).
+// * Every chunk before `tail` must be full: its first `ChunkRef::usable` items
+//   are initialized. `gaps` is the total number of unusable slots in these
+//   chunks.
+// * `tail` and every chunk after it have `ChunkRef::CAPACITY` usable slots.
+// * Every chunk after `tail` is a spare chunk allocated by `Self::reserve` and
+//   contains no initialized items.
+// * `tail` is `None` if and only if `head` is `None`, in which case `tail_len`
+//   is `Self::CHUNK_SIZE`.
 // * If `tail` is `Some`, the items in `tail` up to index `tail_len`
 //   (exclusive) are initialized.
+// * If `head` is `Some`, `rc` has been initialized with `init_rc`.
 //
 /// Like [`Arena`], but returns references of any lifetime, including
 /// `'static`.
@@ -76,6 +263,12 @@ pub struct ManuallyDropArena<T, Options: ArenaOptions<T> = super::Options> {
     tail: Option<ArenaChunk<T, Options>>,
     tail_len: usize,
     len: usize,
+    /// The number of slots left unused at the end of chunks before the tail.
+    gaps: usize,
+    /// The number of allocated chunks.
+    chunks: usize,
+    #[cfg(feature = "stats")]
+    stats: Stats,
     /// Lets dropck know that `T` may be dropped.
     phantom: PhantomData<Box<T>>,
 }
@@ -89,6 +282,15 @@ impl<T, Options: ArenaOptions<T>> Default for ManuallyDropArena<T, Options> {
 impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
     const CHUNK_SIZE: usize = ArenaChunk::<T, Options>::CAPACITY;
 
+    /// The maximum number of items the arena can hold. Zero-sized items are
+    /// limited to a single chunk, as their chunks all share the same range of
+    /// (dangling) addresses.
+    const MAX_LEN: usize = if mem::size_of::<T>() == 0 {
+        Self::CHUNK_SIZE
+    } else {
+        usize::MAX
+    };
+
     /// Creates a new [`ManuallyDropArena`].
     pub fn new() -> Self {
         Self {
@@ -97,44 +299,506 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
             tail: None,
             tail_len: Self::CHUNK_SIZE,
             len: 0,
+            gaps: 0,
+            chunks: 0,
+            #[cfg(feature = "stats")]
+            stats: Stats::default(),
             phantom: PhantomData,
         }
     }
 
-    fn ensure_free_space(&mut self) -> Result<(), impl Debug + Display> {
+    /// Creates a new arena containing the items in `vec`, in order.
+    ///
+    /// Space for all of the items is reserved up front, and the items are
+    /// moved into the arena a chunk at a time. The vector's heap buffer is
+    /// freed afterward.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn from_vec(mut vec: Vec<T>) -> Self {
+        // SAFETY: `vec` contains `vec.len()` initialized items.
+        let arena = unsafe { Self::move_from(vec.as_ptr(), vec.len()) };
+        // SAFETY: The items have been moved into the arena, so `vec` must no
+        // longer drop them.
+        unsafe {
+            vec.set_len(0);
+        }
+        arena
+    }
+
+    /// Creates a new arena and moves the `len` items starting at `src` into
+    /// it, a chunk at a time.
+    ///
+    /// # Safety
+    ///
+    /// `src` must point to `len` initialized items, which the caller must
+    /// treat as moved-from once this function returns.
+    unsafe fn move_from(mut src: *const T, len: usize) -> Self {
+        let mut arena = Self::new();
+        arena.reserve(len);
+
+        let mut remaining = len;
+        for chunk in successors(arena.head.clone(), ChunkRef::next) {
+            let count = remaining.min(Self::CHUNK_SIZE);
+            // SAFETY: `src` points to `remaining` initialized items, and we
+            // reserved enough space for all of them, so the chunk has room
+            // for `count` items. 0 is always less than or equal to the chunk
+            // capacity.
+            unsafe {
+                ptr::copy_nonoverlapping(src, chunk.get(0).as_ptr(), count);
+                src = src.add(count);
+            }
+            remaining -= count;
+        }
+
+        // SAFETY: The first `len` slots in the arena are now initialized.
+        unsafe {
+            arena.set_len(len);
+        }
+        arena
+    }
+
+    /// Appends copies of the items in `src` to the arena, in order.
+    ///
+    /// Space for all of the items is reserved up front, and the items are
+    /// copied into the arena a chunk at a time, which is faster than
+    /// allocating them one at a time.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn extend_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+        Options: ArenaOptions<T, Mutable = Bool<true>>,
+    {
+        self.reserve(src.len());
+        let (first, mut offset) = match &self.tail {
+            Some(tail) if self.tail_len < Self::CHUNK_SIZE => {
+                (Some(tail.clone()), self.tail_len)
+            }
+            Some(tail) => (tail.next(), 0),
+            None => (self.head.clone(), 0),
+        };
+
+        let mut remaining = src;
+        for chunk in successors(first, ChunkRef::next) {
+            if remaining.is_empty() {
+                break;
+            }
+            let count = remaining.len().min(Self::CHUNK_SIZE - offset);
+            // SAFETY: We reserved enough space for all of `src`, and every
+            // slot from `offset` to the end of this chunk is free, so the
+            // chunk has room for `count` items. `offset` is always less than
+            // or equal to the chunk capacity.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    remaining.as_ptr(),
+                    chunk.get(offset).as_ptr(),
+                    count,
+                );
+            }
+            remaining = &remaining[count..];
+            offset = 0;
+        }
+
+        // SAFETY: The `src.len()` slots after the current length are now
+        // initialized.
+        unsafe {
+            self.set_len(self.len + src.len());
+        }
+    }
+
+    /// Moves the chunks at index `chunk_index` and above (i.e., the
+    /// `chunk_index`-th chunk of memory allocated by this arena and every
+    /// chunk after it) into a new arena, which is returned. Items in those
+    /// chunks are moved with them.
+    ///
+    /// Both arenas must be dropped separately. All existing [`Position`]s for
+    /// this arena are invalidated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_index` is greater than the number of allocated chunks.
+    ///
+    /// # Safety
+    ///
+    /// There must be no instances of [`Iter`] or [`IterMut`] for this arena,
+    /// as they could otherwise continue into chunks that now belong to the
+    /// returned arena.
+    #[must_use]
+    pub unsafe fn split_off_chunks(&mut self, chunk_index: usize) -> Self {
+        assert!(chunk_index <= self.chunks, "chunk index out of bounds");
+        if chunk_index == self.chunks {
+            return Self::new();
+        }
+
+        let mut other = Self::new();
+        if chunk_index == 0 {
+            mem::swap(self, &mut other);
+            #[cfg(feature = "stats")]
+            mem::swap(&mut self.stats, &mut other.stats);
+        } else {
+            let mut tail_moved = true;
+            let mut split_len = 0;
+            let mut split_gaps = 0;
+            let mut prev = successors(self.head.clone(), ChunkRef::next)
+                .take(chunk_index)
+                .inspect(|chunk| {
+                    tail_moved &= self.tail.as_ref() != Some(chunk);
+                    split_len += chunk.usable();
+                    split_gaps += Self::CHUNK_SIZE - chunk.usable();
+                })
+                .last()
+                .unwrap_or_else(|| {
+                    unreachable!("arena should have `chunk_index` chunks");
+                });
+            let head = prev.next();
+            prev.set_next(None);
+
+            // If the tail was moved, every chunk that remains is full, and the
+            // last one becomes the new tail.
+            if tail_moved {
+                let prev_len = prev.usable();
+                prev.set_usable(Self::CHUNK_SIZE);
+                split_gaps -= Self::CHUNK_SIZE - prev_len;
+                other.tail = self.tail.replace(prev);
+                other.tail_len = mem::replace(&mut self.tail_len, prev_len);
+                other.len = mem::replace(&mut self.len, split_len) - split_len;
+                other.gaps = mem::replace(&mut self.gaps, split_gaps)
+                    - split_gaps
+                    - (Self::CHUNK_SIZE - prev_len);
+            } else {
+                other.tail = head.clone();
+                other.tail_len = 0;
+            }
+            other.head = head;
+            other.chunks = self.chunks - chunk_index;
+            self.chunks = chunk_index;
+        }
+
+        // Positions for this arena may refer to chunks that are now part of
+        // `other`.
+        self.rc = None;
+        if self.head.is_some() {
+            SupportsPositions::<T, Options>::init_rc(&mut self.rc);
+        }
+        other.rc = None;
+        SupportsPositions::<T, Options>::init_rc(&mut other.rc);
+        other
+    }
+
+    /// Frees all but the first `keep` chunks of an empty arena, so that
+    /// later allocations can reuse the remaining chunks without allocating
+    /// them again. Does nothing if the arena has `keep` or fewer chunks.
+    /// Otherwise, all existing [`Position`]s for this arena are invalidated.
+    ///
+    /// This is meant to be called after the arena's items have been dropped
+    /// or moved out (e.g., with [`Self::set_len`]). If the arena isn't empty,
+    /// chunks that contain items are always kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the arena isn't empty.
+    ///
+    /// # Safety
+    ///
+    /// Same requirements as [`Self::split_off_chunks`].
+    pub unsafe fn retain_chunks(&mut self, keep: usize) {
+        debug_assert!(self.len == 0, "arena is not empty");
+        let keep = keep.max(self.chunks_with_len().count());
+        if keep >= self.chunks {
+            return;
+        }
+        // SAFETY: Checked by caller.
+        let mut spare = unsafe { self.split_off_chunks(keep) };
+        // SAFETY: `spare` contains only chunks after the ones that contain
+        // items, so it has no items, and it was just created, so no iterators
+        // for it exist.
+        unsafe {
+            spare.drop();
+        }
+    }
+
+    /// Returns the number of chunks needed to hold `items` items.
+    ///
+    /// If the chunk size is 0, no number of chunks can hold any items, so
+    /// this returns [`usize::MAX`] unless `items` is 0.
+    pub const fn chunks_needed(items: usize) -> usize {
+        if items == 0 {
+            return 0;
+        }
+        match (items - 1).checked_div(Self::CHUNK_SIZE) {
+            Some(n) => n + 1,
+            None => usize::MAX,
+        }
+    }
+
+    fn ensure_free_space(&mut self) -> Result<(), AllocFailure> {
         assert!(
             Self::CHUNK_SIZE > 0,
             "cannot allocate items when chunk size is 0",
         );
+        if self.len == Self::MAX_LEN {
+            return Err(AllocFailure::LenOverflow);
+        }
         if self.tail_len < Self::CHUNK_SIZE {
-            // `self.tail` cannot be `None`. The only time `self.tail` is
-            // `None` is after calling `Self::new`, which also sets
-            // `self.tail_len` to `Self::CHUNK_SIZE`.
+            // `self.tail` cannot be `None`. `self.tail` is `None` only when
+            // `self.tail_len` is `Self::CHUNK_SIZE`.
             return Ok(());
         }
 
-        let chunk = if let Some(chunk) = ChunkRef::new(self.tail.take()) {
+        if let Some(next) = self.tail.as_ref().and_then(ChunkRef::next) {
+            // Use a spare chunk allocated by `Self::reserve`.
+            self.tail = Some(next);
+            self.tail_len = 0;
+            return Ok(());
+        }
+
+        let chunk = self.alloc_chunk(self.tail.clone())?;
+        self.tail = Some(chunk);
+        self.tail_len = 0;
+        Ok(())
+    }
+
+    /// Allocates a new chunk and links it after `prev`, which must be the
+    /// last chunk in the list (or [`None`] if the list is empty).
+    fn alloc_chunk(
+        &mut self,
+        prev: Option<ArenaChunk<T, Options>>,
+    ) -> Result<ArenaChunk<T, Options>, AllocFailure> {
+        if self.chunks >= MaxChunks::<T, Options>::MAX {
+            return Err(AllocFailure::ChunkLimit);
+        }
+
+        let chunk = if let Some(chunk) = ChunkRef::new(prev) {
             chunk
         } else {
-            return Err("could not allocate chunk");
+            return Err(AllocFailure::Oom);
         };
 
+        SupportsPositions::<T, Options>::init_rc(&mut self.rc);
         self.head.get_or_insert_with(|| chunk.clone());
-        self.tail = Some(chunk);
-        self.tail_len = 0;
-        Ok(())
+        self.chunks += 1;
+        #[cfg(feature = "stats")]
+        self.stats.record_chunk(self.chunks);
+        Ok(chunk)
     }
 
-    fn alloc_ptr(&mut self, value: T) -> NonNull<T> {
-        self.try_alloc_ptr(value).unwrap_or_else(|| {
-            handle_alloc_error(ArenaChunk::<T, Options>::LAYOUT);
-        })
+    #[cold]
+    fn handle_alloc_failure(failure: AllocFailure) -> ! {
+        match failure {
+            AllocFailure::Oom => {
+                handle_alloc_error(ArenaChunk::<T, Options>::LAYOUT);
+            }
+            AllocFailure::ChunkLimit => {
+                panic!("arena cannot allocate more than `MaxChunks` chunks");
+            }
+            AllocFailure::LenOverflow => {
+                panic!("arena cannot hold any more items");
+            }
+        }
+    }
+
+    pub(crate) fn alloc_ptr(&mut self, value: T) -> NonNull<T> {
+        if let Err(failure) = self.ensure_free_space() {
+            Self::handle_alloc_failure(failure);
+        }
+        // SAFETY: We just called `Self::ensure_free_space`.
+        unsafe { self.push_unchecked(value) }
+    }
+
+    /// Allocates a new item and writes the result of `f` directly into its
+    /// slot.
+    ///
+    /// The slot is claimed before `f` is called, and `this` isn't borrowed
+    /// while `f` runs, so `f` may allocate more items in the arena; they are
+    /// placed after the slot. If `f` panics, no item is allocated. However,
+    /// if `f` allocated items before panicking, the slot can't be given back
+    /// without leaving a hole, so the arena's items and chunks are leaked and
+    /// the arena is left empty.
+    ///
+    /// # Safety
+    ///
+    /// * `this` must be valid for reads and writes for the duration of the
+    ///   call. While `f` runs, the arena may be accessed only through `this`.
+    /// * No code may read the arena's items while `f` runs, as the claimed
+    ///   slot is still uninitialized.
+    pub(crate) unsafe fn alloc_ptr_with<F: FnOnce() -> T>(
+        this: *mut Self,
+        f: F,
+    ) -> NonNull<T> {
+        struct Guard<T, Options: ArenaOptions<T>> {
+            arena: *mut ManuallyDropArena<T, Options>,
+            len: usize,
+        }
+
+        impl<T, Options: ArenaOptions<T>> Drop for Guard<T, Options> {
+            fn drop(&mut self) {
+                // Only runs if `f` panicked.
+                // SAFETY: Checked by caller of `alloc_ptr_with`.
+                let arena = unsafe { &mut *self.arena };
+                if arena.len == self.len {
+                    // No items were allocated after the slot, so it's still
+                    // the last one.
+                    arena.tail_len -= 1;
+                    arena.len -= 1;
+                } else {
+                    // Items after the slot may still be referenced, so they
+                    // can't be dropped, and their memory can't be reused.
+                    // Replacing the arena leaks its chunks.
+                    *arena = ManuallyDropArena::new();
+                }
+            }
+        }
+
+        let (item, len) = {
+            // SAFETY: Checked by caller.
+            let arena = unsafe { &mut *this };
+            if let Err(failure) = arena.ensure_free_space() {
+                Self::handle_alloc_failure(failure);
+            }
+            let chunk = arena.tail.as_ref().unwrap_or_else(|| {
+                // SAFETY: `Self::ensure_free_space` ensures that `self.tail`
+                // is not `None`.
+                unsafe { unreachable_unchecked() }
+            });
+
+            // SAFETY: `Self::ensure_free_space` ensures that `self.tail_len`
+            // is less than the chunk size.
+            let item = unsafe { chunk.get(arena.tail_len) };
+            arena.tail_len += 1;
+            arena.len += 1;
+            (item, arena.len)
+        };
+
+        let guard = Guard {
+            arena: this,
+            len,
+        };
+        // SAFETY: `ChunkRef::get` returns valid, properly aligned pointers,
+        // and the slot was claimed above, so no other item is written to it.
+        unsafe {
+            item.as_ptr().write(f());
+        }
+        mem::forget(guard);
+
+        #[cfg(feature = "stats")]
+        {
+            // SAFETY: Checked by caller.
+            let arena = unsafe { &mut *this };
+            arena.stats.record_items(1, arena.len);
+        }
+        item
     }
 
     fn try_alloc_ptr(&mut self, value: T) -> Option<NonNull<T>> {
         self.ensure_free_space().ok()?;
-        SupportsPositions::<T, Options>::init_rc(&mut self.rc);
+        // SAFETY: We just called `Self::ensure_free_space`.
+        Some(unsafe { self.push_unchecked(value) })
+    }
+
+    fn try_alloc_ptr_detailed(
+        &mut self,
+        value: T,
+    ) -> Result<NonNull<T>, (T, AllocFailureKind)> {
+        match self.ensure_free_space() {
+            // SAFETY: We just called `Self::ensure_free_space`.
+            Ok(()) => Ok(unsafe { self.push_unchecked(value) }),
+            Err(failure) => Err((value, failure.into())),
+        }
+    }
+
+    /// Writes `values` to consecutive slots in a single chunk. If the tail
+    /// chunk has free space but not enough for all of the items, the rest of
+    /// it is left unused and the items are written to the next chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is greater than [`Self::CHUNK_SIZE`].
+    fn alloc_array_ptr_impl<const N: usize>(
+        &mut self,
+        values: [T; N],
+    ) -> Result<NonNull<[T; N]>, AllocFailure> {
+        assert!(
+            N <= Self::CHUNK_SIZE,
+            "array length cannot be greater than the chunk size",
+        );
+        if N == 0 {
+            return Ok(NonNull::dangling());
+        }
+        if Self::MAX_LEN - self.len < N {
+            return Err(AllocFailure::LenOverflow);
+        }
+        if self.tail_len < Self::CHUNK_SIZE
+            && Self::CHUNK_SIZE - self.tail_len < N
+        {
+            let mut tail = self.tail.clone().unwrap_or_else(|| {
+                // SAFETY: `self.tail` is `None` only when `self.tail_len` is
+                // `Self::CHUNK_SIZE`.
+                unsafe { unreachable_unchecked() }
+            });
+            if tail.next().is_none() {
+                self.alloc_chunk(Some(tail.clone()))?;
+            }
+            // Leave the rest of the tail chunk unused, so that
+            // `Self::ensure_free_space` moves on to the next chunk.
+            tail.set_usable(self.tail_len);
+            self.gaps += Self::CHUNK_SIZE - self.tail_len;
+            self.tail_len = Self::CHUNK_SIZE;
+        }
+        self.ensure_free_space()?;
+
+        let chunk = self.tail.as_mut().unwrap_or_else(|| {
+            // SAFETY: `Self::ensure_free_space` ensures that `self.tail`
+            // is not `None`.
+            unsafe { unreachable_unchecked() }
+        });
+
+        // SAFETY: `Self::ensure_free_space` ensures that `self.tail_len` is
+        // less than the chunk size, and the rest of the chunk can hold `N`
+        // items: either it did above, or the tail chunk was just changed to
+        // an empty one. Consecutive items in a chunk are laid out like an
+        // array.
+        let ptr = unsafe { chunk.get(self.tail_len) }.cast::<[T; N]>();
+
+        // SAFETY: `ChunkRef::get` returns valid, properly aligned pointers.
+        unsafe {
+            ptr.as_ptr().write(values);
+        }
+
+        self.tail_len += N;
+        self.len += N;
+        #[cfg(feature = "stats")]
+        self.stats.record_items(N, self.len);
+        Ok(ptr)
+    }
+
+    pub(crate) fn alloc_array_ptr<const N: usize>(
+        &mut self,
+        values: [T; N],
+    ) -> NonNull<[T; N]> {
+        match self.alloc_array_ptr_impl(values) {
+            Ok(ptr) => ptr,
+            Err(failure) => Self::handle_alloc_failure(failure),
+        }
+    }
 
+    pub(crate) fn try_alloc_array_ptr<const N: usize>(
+        &mut self,
+        values: [T; N],
+    ) -> Option<NonNull<[T; N]>> {
+        self.alloc_array_ptr_impl(values).ok()
+    }
+
+    /// Writes `value` to the next free slot in the tail chunk.
+    ///
+    /// # Safety
+    ///
+    /// [`Self::ensure_free_space`] must have been called and returned [`Ok`],
+    /// and no other methods that modify the arena may have been called since.
+    unsafe fn push_unchecked(&mut self, value: T) -> NonNull<T> {
         let chunk = self.tail.as_mut().unwrap_or_else(|| {
             // SAFETY: `Self::ensure_free_space` ensures that `self.tail`
             // is not `None`.
@@ -152,12 +816,18 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
 
         self.tail_len += 1;
         self.len += 1;
-        Some(item)
+        #[cfg(feature = "stats")]
+        self.stats.record_items(1, self.len);
+        item
     }
 
     /// Drops the contents of the arena. The arena will leak memory when
     /// dropped unless this method is called.
     ///
+    /// If an item's destructor panics, the remaining items are still dropped
+    /// and all memory is still freed before the panic is propagated. Either
+    /// way, the arena is left empty and can be reused.
+    ///
     /// # Safety
     ///
     /// You must ensure that no references to items (or parts of items) in the
@@ -178,51 +848,121 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
     ///
     /// [dropck]: https://doc.rust-lang.org/nomicon/dropck.html
     pub unsafe fn drop(&mut self) {
-        let mut head = if let Some(head) = self.head.take() {
-            head
+        let (head, len) = if let Some(chunks) = self.take_chunks() {
+            chunks
         } else {
             return;
         };
 
+        // SAFETY: `self.head` and `self.tail` are both `None`, so the list of
+        // chunks is owned exclusively by `head`. Every chunk before the tail
+        // is full and no chunk after it contains items, so the first `len`
+        // items in the list are initialized. The caller guarantees the items
+        // are safe to drop and that no iterators exist.
+        unsafe {
+            drop_chunks::<_, _, _, true>(head, len);
+        }
+    }
+
+    /// Frees the memory of an empty arena without dropping any items. All
+    /// existing [`Position`]s for this arena are invalidated.
+    ///
+    /// This is meant for arenas whose items have all been moved out (e.g.,
+    /// by reading them through [`Self::slot_ptr`] and then calling
+    /// [`Self::set_len`] with 0), where [`Self::drop`] would have nothing left
+    /// to drop but the chunks still need to be freed. If the arena isn't
+    /// empty, its items are leaked.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the arena isn't empty.
+    ///
+    /// # Safety
+    ///
+    /// There must be no instances of [`Iter`] or [`IterMut`] for this arena.
+    pub unsafe fn dealloc_empty(&mut self) {
+        debug_assert!(self.len == 0, "arena is not empty");
+        let (head, _) = if let Some(chunks) = self.take_chunks() {
+            chunks
+        } else {
+            return;
+        };
+
+        // SAFETY: `self.head` and `self.tail` are both `None`, so the list of
+        // chunks is owned exclusively by `head`. No items are dropped, and the
+        // caller guarantees that no iterators exist.
+        unsafe {
+            drop_chunks::<_, _, _, true>(head, 0);
+        }
+    }
+
+    /// Resets the arena to its initial state and returns its list of chunks
+    /// (now owned exclusively by the caller) and its previous length, or
+    /// [`None`] if no chunks were allocated.
+    fn take_chunks(&mut self) -> Option<(ArenaChunk<T, Options>, usize)> {
+        let head = self.head.take()?;
         self.tail = None;
-        let tail_len = mem::replace(&mut self.tail_len, Self::CHUNK_SIZE);
-        self.len = 0;
+        self.tail_len = Self::CHUNK_SIZE;
+        let len = mem::replace(&mut self.len, 0);
+        self.gaps = 0;
+        self.chunks = 0;
         self.rc = None;
+        Some((head, len))
+    }
 
-        // Drop the items in all chunks except the last.
-        while let Some(next) = head.next() {
-            // SAFETY: All chunks except for the tail are guaranteed to
-            // be full (all items initialized). We know this isn't the
-            // tail chunk because `head.next()` is not `None`.
-            unsafe {
-                head.drop_all();
-            }
+    /// Drops the items in the arena but keeps its chunks, so that later
+    /// allocations reuse them instead of allocating new chunks. Unlike
+    /// [`Self::drop`], this doesn't free any memory. All existing
+    /// [`Position`]s for this arena are invalidated, unless the arena was
+    /// already empty, in which case this method does nothing.
+    ///
+    /// If an item's destructor panics, the remaining items are still dropped
+    /// before the panic is propagated. Either way, the arena is left empty.
+    ///
+    /// # Safety
+    ///
+    /// Same requirements as [`Self::drop`].
+    pub unsafe fn clear(&mut self) {
+        let head = if let Some(head) = self.head.clone() {
+            head
+        } else {
+            return;
+        };
 
-            // SAFETY: No clones of this `ChunkRef` exist. `self.head`
-            // and `self.tail` are both `None`, and the chunks form a
-            // singly linked list. Caller guarantees no iterators exist.
-            unsafe {
-                head.dealloc();
-            }
-            head = next;
+        let len = self.len;
+        if len > 0 {
+            // Invalidate positions that refer to the items.
+            self.rc = None;
+            SupportsPositions::<T, Options>::init_rc(&mut self.rc);
         }
 
-        // `head` is now the tail chunk; drop its items.
-        for i in 0..tail_len {
-            // SAFETY: The items in the tail chunk (when not `None`) at
-            // indices up to `self.tail_len` are always initialized.
-            unsafe {
-                head.drop_item(i);
-            }
-        }
+        // Like `Self::set_len(0)`, but leaves the chunks' usable slots for
+        // `drop_chunks` to reset, since it needs them to find the items.
+        self.tail = Some(head.clone());
+        self.tail_len = 0;
+        self.len = 0;
+        self.gaps = 0;
 
-        // SAFETY: No clones of this `ChunkRef` exist for the same
-        // reasons as the other chunks above.
+        // SAFETY: The arena no longer considers the first `len` items in the
+        // list initialized, so they won't be accessed again. They were
+        // initialized, and the caller guarantees they are safe to drop and
+        // that no iterators exist.
         unsafe {
-            head.dealloc();
+            drop_chunks::<_, _, _, false>(head, len);
         }
     }
 
+    /// Checks whether the arena owns memory that will be leaked if the arena
+    /// is dropped without first calling [`Self::drop`]. This is true if the
+    /// arena contains any items or has reserved any chunks (see
+    /// [`Self::reserve`]).
+    ///
+    /// This can be used to detect leaks, e.g., by checking it in a debug
+    /// assertion before the arena goes out of scope.
+    pub const fn has_undropped_items(&self) -> bool {
+        self.head.is_some()
+    }
+
     /// Alias of [`Self::drop`]. Can be used to prevent name collisions when
     /// this arena is stored in a [`Deref`](core::ops::Deref) type:
     ///
@@ -243,16 +983,154 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
         }
     }
 
+    /// Returns the approximate number of additional bytes of memory used by
+    /// an arena of this type (not including its iterators) to support
+    /// [`Position`]s, as configured by [`ArenaOptions::SupportsPositions`].
+    ///
+    /// This is 0 if positions are not supported.
+    pub fn positions_overhead() -> usize {
+        mem::size_of::<Option<ArenaRc<T, Options>>>()
+            + SupportsPositions::<T, Options>::RC_ALLOC_SIZE
+    }
+
+    /// Reserves space for at least `additional` more items, so that the next
+    /// `additional` allocations won't need to allocate new chunks.
+    ///
+    /// Reserving space never moves existing items, so references returned by
+    /// [`Self::alloc`] remain valid. (In contrast, methods that drop or
+    /// forget items, like [`Self::drop`] and [`Self::set_len`], are unsafe:
+    /// because references returned by this type can have any lifetime, the
+    /// caller must ensure none of them are still in use.)
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails;
+    /// for a version that returns [`None`], see [`Self::try_reserve`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if reserving the space would require more than
+    /// [`ArenaOptions::MaxChunks`] chunks.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(failure) = self.reserve_chunks(additional) {
+            Self::handle_alloc_failure(failure);
+        }
+    }
+
+    /// Like [`Self::reserve`], but returns [`None`] if memory allocation
+    /// fails or the chunk limit would be exceeded. Any chunks allocated
+    /// before the failure are kept for later allocations.
+    pub fn try_reserve(&mut self, additional: usize) -> Option<()> {
+        self.reserve_chunks(additional).ok()
+    }
+
+    fn reserve_chunks(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), AllocFailure> {
+        // All chunks before the tail are full and all chunks after it are
+        // empty, so every usable slot past the first `self.len` is free.
+        let free = self.capacity() - self.len;
+        if additional <= free {
+            return Ok(());
+        }
+        if additional > Self::MAX_LEN - self.len {
+            return Err(AllocFailure::LenOverflow);
+        }
+
+        assert!(
+            Self::CHUNK_SIZE > 0,
+            "cannot allocate items when chunk size is 0",
+        );
+
+        let needed = Self::chunks_needed(additional - free);
+        if needed > MaxChunks::<T, Options>::MAX - self.chunks {
+            return Err(AllocFailure::ChunkLimit);
+        }
+
+        let mut last = successors(self.tail.clone(), ChunkRef::next).last();
+        for _ in 0..needed {
+            let chunk = self.alloc_chunk(last)?;
+            if self.tail.is_none() {
+                self.tail = Some(chunk.clone());
+                self.tail_len = 0;
+            }
+            last = Some(chunk);
+        }
+        Ok(())
+    }
+
+    /// Returns the fraction of the tail chunk (the chunk that the next item
+    /// will be allocated in, if it has space) that is occupied by items, from
+    /// 0.0 to 1.0. Returns 0.0 if the arena hasn't allocated any chunks.
+    ///
+    /// Chunks are never freed while the arena is in use, even if
+    /// [`Self::set_len`] shrinks the arena; chunks left empty are kept and
+    /// reused by later allocations. Repeatedly growing and shrinking the
+    /// arena around a chunk boundary therefore doesn't cause repeated calls
+    /// to the allocator.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn tail_utilization(&self) -> f32 {
+        if self.tail.is_none() {
+            return 0.0;
+        }
+        self.tail_len as f32 / Self::CHUNK_SIZE as f32
+    }
+
+    /// Returns cumulative allocation statistics for this arena.
+    ///
+    /// This method is available only with the crate feature `stats`.
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
     /// Returns the total number of items that have been allocated.
-    pub fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         self.len
     }
 
     /// Checks whether the arena is empty.
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.len == 0
     }
 
+    /// Returns the number of items that can be allocated before an item has
+    /// to be placed in a different chunk than the next item. If the tail
+    /// chunk is full, this is the capacity of the chunk that will be used
+    /// next.
+    pub(crate) fn contiguous_free(&self) -> usize {
+        if self.tail_len < Self::CHUNK_SIZE {
+            Self::CHUNK_SIZE - self.tail_len
+        } else {
+            Self::CHUNK_SIZE
+        }
+    }
+
+    /// Returns the number of chunks the arena has allocated, including spare
+    /// chunks allocated by [`Self::reserve`].
+    pub const fn chunk_count(&self) -> usize {
+        self.chunks
+    }
+
+    /// Returns the number of items the arena can hold without allocating
+    /// another chunk. This is 0 if the arena hasn't allocated any chunks.
+    ///
+    /// Slots left unused by [`Arena::alloc_array`] aren't counted.
+    pub const fn capacity(&self) -> usize {
+        self.chunks * Self::CHUNK_SIZE - self.gaps
+    }
+
+    /// Returns the number of bytes of memory the arena has requested from the
+    /// global allocator for its chunks. Each chunk's allocation includes a
+    /// pointer to the next chunk and any padding required for alignment.
+    ///
+    /// This doesn't include the small allocation used to support
+    /// [`Position`]s (see [`Self::positions_overhead`]).
+    pub const fn bytes_allocated(&self) -> usize {
+        self.chunks * ArenaChunk::<T, Options>::LAYOUT.size()
+    }
+
     /// Allocates a new item in the arena and initializes it with `value`.
     /// Returns a reference to the allocated item. The reference can have any
     /// lifetime, including `'static`, as long as `T` outlives that lifetime.
@@ -263,7 +1141,8 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
     /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
     pub fn alloc<'a>(&mut self, value: T) -> &'a mut T
     where
-        Options: 'a + ArenaOptions<T, Mutable = Bool<true>>,
+        Options:
+            'a + ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
     {
         // SAFETY: `Self::alloc_ptr` returns initialized, properly aligned
         // pointers, and we can return a reference with an arbitrary lifetime
@@ -271,13 +1150,58 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
         unsafe { self.alloc_ptr(value).as_mut() }
     }
 
-    /// Like [`Self::alloc`], but returns [`None`] if memory allocation fails.
-    pub fn try_alloc<'a>(&mut self, value: T) -> Option<&'a mut T>
+    /// Like [`Self::alloc`], but returns [`None`] if memory allocation fails.
+    pub fn try_alloc<'a>(&mut self, value: T) -> Option<&'a mut T>
+    where
+        Options:
+            'a + ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+    {
+        // SAFETY: See `Self::alloc`.
+        Some(unsafe { self.try_alloc_ptr(value)?.as_mut() })
+    }
+
+    /// Like [`Self::try_alloc`], but reports why the allocation failed.
+    ///
+    /// # Errors
+    ///
+    /// If the item can't be allocated, returns `value` (which is not written
+    /// to the arena) along with an [`AllocFailureKind`] describing the
+    /// failure.
+    pub fn try_alloc_detailed<'a>(
+        &mut self,
+        value: T,
+    ) -> Result<&'a mut T, (T, AllocFailureKind)>
+    where
+        Options:
+            'a + ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+    {
+        // SAFETY: See `Self::alloc`.
+        self.try_alloc_ptr_detailed(value).map(|p| unsafe { &mut *p.as_ptr() })
+    }
+
+    /// Allocates a new item in the arena and initializes it with the result of
+    /// `f`. Returns a reference to the allocated item. The reference can have
+    /// any lifetime, including `'static`, as long as `T` outlives that
+    /// lifetime.
+    ///
+    /// Unlike [`Self::alloc`], the item's slot is located before `f` is
+    /// called, and the result of `f` is written directly into it. For large
+    /// types, this lets the compiler avoid constructing the item on the stack
+    /// first. If `f` panics, no item is allocated.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn alloc_with<'a, F>(&mut self, f: F) -> &'a mut T
     where
-        Options: 'a + ArenaOptions<T, Mutable = Bool<true>>,
+        Options:
+            'a + ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+        F: FnOnce() -> T,
     {
-        // SAFETY: See `Self::alloc`.
-        Some(unsafe { self.try_alloc_ptr(value)?.as_mut() })
+        // SAFETY: `self` is a valid mutable reference, which `f` can't
+        // access, and the returned pointer is initialized and properly
+        // aligned. See also `Self::alloc`.
+        unsafe { Self::alloc_ptr_with(self, f).as_mut() }
     }
 
     /// Allocates a new item in the arena and initializes it with `value`.
@@ -297,6 +1221,20 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
         unsafe { self.alloc_ptr(value).as_ref() }
     }
 
+    /// Like [`Self::alloc_with`], but returns a shared/immutable reference.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn alloc_shared_with<'a, F>(&mut self, f: F) -> &'a T
+    where
+        Options: 'a,
+        F: FnOnce() -> T,
+    {
+        // SAFETY: See `Self::alloc_with`.
+        unsafe { Self::alloc_ptr_with(self, f).as_ref() }
+    }
+
     /// Like [`Self::alloc_shared`], but returns [`None`] if memory allocation
     /// fails.
     pub fn try_alloc_shared<'a>(&mut self, value: T) -> Option<&'a T>
@@ -307,6 +1245,48 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
         Some(unsafe { self.try_alloc_ptr(value)?.as_ref() })
     }
 
+    /// Like [`Self::try_alloc_shared`], but reports why the allocation failed.
+    ///
+    /// # Errors
+    ///
+    /// If the item can't be allocated, returns `value` (which is not written
+    /// to the arena) along with an [`AllocFailureKind`] describing the
+    /// failure.
+    pub fn try_alloc_shared_detailed<'a>(
+        &mut self,
+        value: T,
+    ) -> Result<&'a T, (T, AllocFailureKind)>
+    where
+        Options: 'a,
+    {
+        // SAFETY: See `Self::alloc`.
+        self.try_alloc_ptr_detailed(value).map(|p| unsafe { &*p.as_ptr() })
+    }
+
+    /// Appends every item produced by `iter` to the arena, in order, and
+    /// returns the number of items added.
+    ///
+    /// Space for the lower bound of `iter`'s [`size_hint`] is reserved up
+    /// front.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`size_hint`]: Iterator::size_hint
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn collect_from<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        let mut count = 0;
+        for value in iter {
+            self.alloc_ptr(value);
+            count += 1;
+        }
+        count
+    }
+
     fn end(&self) -> *const T {
         self.tail.as_ref().map_or(ptr::null(), |c| {
             // SAFETY: `self.tail_len` is necessarily less than or equal to
@@ -315,17 +1295,227 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
         })
     }
 
+    /// Returns an iterator over the chunks that contain items, along with
+    /// the number of items in each chunk.
+    fn chunks_with_len(
+        &self,
+    ) -> impl Iterator<Item = (ArenaChunk<T, Options>, usize)> {
+        let mut remaining = self.len;
+        successors(self.head.clone(), ChunkRef::next).map_while(move |chunk| {
+            if remaining == 0 {
+                return None;
+            }
+            let len = remaining.min(chunk.usable());
+            remaining -= len;
+            Some((chunk, len))
+        })
+    }
+
+    /// Returns an iterator over pointers to the first item in each chunk that
+    /// contains items, along with the number of items in the chunk.
+    pub(crate) fn chunk_starts(
+        &self,
+    ) -> impl Iterator<Item = (*mut T, usize)> + '_ {
+        self.chunks_with_len().map(|(chunk, len)| {
+            // SAFETY: 0 is always less than or equal to the chunk capacity.
+            (unsafe { chunk.get(0) }.as_ptr(), len)
+        })
+    }
+
+    /// Returns an iterator over pointers to the initialized items in each
+    /// chunk that contains items.
+    pub(crate) fn chunk_ptrs(&self) -> impl Iterator<Item = *mut [T]> + '_ {
+        self.chunk_starts()
+            .map(|(start, len)| ptr::slice_from_raw_parts_mut(start, len))
+    }
+
+    /// Returns a pointer to the item at index `index`, or [`None`] if `index`
+    /// is out of bounds.
+    ///
+    /// This method runs in O(`index / CHUNK_SIZE`) time.
+    pub(crate) fn get_ptr(&self, index: usize) -> Option<NonNull<T>> {
+        if index >= self.len {
+            return None;
+        }
+        self.slot_ptr(index)
+    }
+
+    /// Returns a pointer to the slot at index `index`, whether or not it
+    /// contains an initialized item, or [`None`] if the arena hasn't
+    /// allocated a chunk containing that slot (see [`Self::reserve`]).
+    ///
+    /// Together with [`Self::set_len`], this can be used to initialize items
+    /// in place. The pointer must not be used to access items in ways that
+    /// would conflict with existing references.
+    ///
+    /// This method runs in O(`index / CHUNK_SIZE`) time.
+    pub fn slot_ptr(&self, mut index: usize) -> Option<NonNull<T>> {
+        if index >= self.capacity() {
+            return None;
+        }
+        for chunk in successors(self.head.clone(), ChunkRef::next) {
+            let usable = chunk.usable();
+            if index < usable {
+                let ptr = chunk.try_get(index);
+                debug_assert!(ptr.is_some());
+                return ptr;
+            }
+            index -= usable;
+        }
+        None
+    }
+
+    /// Sets the number of items in the arena to `new_len`.
+    ///
+    /// If `new_len` is less than the current length, the items at indices
+    /// `new_len` and above are leaked rather than dropped, and all existing
+    /// [`Position`]s for this arena are invalidated.
+    ///
+    /// # Safety
+    ///
+    /// * `new_len` must be less than or equal to the number of allocated slots
+    ///   (i.e., [`Self::slot_ptr`] must return [`Some`] for `new_len - 1`).
+    ///   Use [`Self::reserve`] to allocate more slots.
+    /// * The items at indices from the current length up to `new_len`
+    ///   (exclusive) must be initialized, e.g., through pointers returned by
+    ///   [`Self::slot_ptr`].
+    /// * If `new_len` is less than the current length, there must be no
+    ///   references to the items at indices `new_len` and above or instances
+    ///   of [`Iter`] or [`IterMut`] for this arena.
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        let head = if let Some(head) = self.head.clone() {
+            head
+        } else {
+            return;
+        };
+
+        if new_len < self.len {
+            // Invalidate positions that may refer to removed items.
+            self.rc = None;
+            SupportsPositions::<T, Options>::init_rc(&mut self.rc);
+        }
+
+        #[cfg(feature = "stats")]
+        if let Some(added) = new_len.checked_sub(self.len) {
+            self.stats.record_items(added, new_len);
+        }
+
+        // Find the chunk containing the last item, which becomes the tail.
+        let old_tail = self.tail.take();
+        let mut tail = head;
+        let mut base = 0;
+        self.gaps = 0;
+        loop {
+            let usable = tail.usable();
+            if new_len <= base + usable {
+                break;
+            }
+            tail = if let Some(next) = tail.next() {
+                next
+            } else {
+                // `new_len` is checked by the caller, so this is the last
+                // chunk with usable slots.
+                break;
+            };
+            base += usable;
+            self.gaps += Self::CHUNK_SIZE - usable;
+        }
+
+        // Slots left unused in the new tail and the chunks after it (which
+        // can only be before the old tail) can hold items again.
+        if new_len < self.len {
+            let mut chunk = Some(tail.clone());
+            while let Some(mut current) = chunk {
+                if Some(&current) == old_tail.as_ref() {
+                    break;
+                }
+                chunk = current.next();
+                current.set_usable(Self::CHUNK_SIZE);
+            }
+        }
+
+        self.tail = Some(tail);
+        self.tail_len = new_len - base;
+        self.len = new_len;
+    }
+
     fn iter_ptr<const DROP: bool>(&self) -> IterPtr<T, Options, DROP> {
         IterPtr {
             chunk: self.head.clone(),
             index: 0,
             end: self.end(),
+            offset: 0,
+            remaining: self.len,
+            back: None,
             rc: self.rc.clone(),
             phantom: PhantomData,
         }
     }
 
-    /// Returns an iterator over the items in this arena.
+    /// Calls `f` on each item in the arena, in order, passing a
+    /// [`WorklistArena`] handle so that `f` can allocate more items. Items
+    /// allocated by `f` are processed too, so this method returns once `f`
+    /// has been called on every item, including those allocated during the
+    /// traversal.
+    ///
+    /// This is useful for worklist algorithms, where processing one item may
+    /// produce more items to process.
+    ///
+    /// The handle only allows allocation, so `f` can't replace or shrink the
+    /// arena while it's being traversed:
+    ///
+    /// ```compile_fail
+    /// # use fixed_typed_arena::ManuallyDropArena;
+    /// let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    /// arena.alloc_shared(1_u64);
+    /// arena.drain_worklist(|_, arena| {
+    ///     *arena = ManuallyDropArena::new(); // Error: `arena` is a handle
+    /// });
+    /// ```
+    pub fn drain_worklist<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T, &mut WorklistArena<'_, T, Options>),
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        let mut iter = self.iter_ptr::<false>();
+        loop {
+            // `f` may have allocated more items, so update the end of the
+            // range of items to visit.
+            iter.end = self.end();
+            iter.remaining = self.len - iter.offset;
+            if iter.chunk.is_none() {
+                // The arena was empty when `iter` was created.
+                iter.chunk.clone_from(&self.head);
+            }
+
+            let item = if let Some(item) = iter.next() {
+                item
+            } else {
+                break;
+            };
+            // SAFETY: `IterPtr` returns initialized, properly aligned
+            // pointers. Because `Options::Mutable` is false, no mutable
+            // references to items can exist, and `WorklistArena` only
+            // allows new items to be allocated, which doesn't move, change,
+            // or free existing items.
+            f(unsafe { item.as_ref() }, &mut WorklistArena(self));
+        }
+    }
+
+    /// Returns an iterator over the items in this arena. Like the references
+    /// returned by [`Self::alloc_shared`], the yielded references can have
+    /// any lifetime, including `'static`, as long as `T` outlives that
+    /// lifetime. For example, the items of an arena that is intentionally
+    /// never dropped can be stored in global structures:
+    ///
+    /// ```
+    /// # use fixed_typed_arena::ManuallyDropArena;
+    /// let mut arena = ManuallyDropArena::<_, 8, false, false>::new();
+    /// arena.alloc_shared(1_u32);
+    /// arena.alloc_shared(2);
+    /// let items: Vec<&'static u32> = arena.iter().collect();
+    /// # assert_eq!(items, [&1, &2]);
+    /// ```
     pub fn iter<'a>(&self) -> Iter<'a, T, Options>
     where
         Options: ArenaOptions<T, Mutable = Bool<false>>,
@@ -371,6 +1561,310 @@ impl<T, Options: ArenaOptions<T>> ManuallyDropArena<T, Options> {
     pub unsafe fn into_iter_unchecked(self) -> IntoIter<T, Options> {
         IntoIter(self.iter_ptr())
     }
+
+    /// Converts this arena into an [`Arena`], which drops its items
+    /// automatically.
+    ///
+    /// # Safety
+    ///
+    /// There must be no references to items (or parts of items) in this arena
+    /// or instances of [`Iter`] or [`IterMut`] for this arena. In particular,
+    /// references with a `'static` lifetime returned by [`Self::alloc`] must
+    /// not be used after calling this method, as the returned [`Arena`] will
+    /// drop the items they refer to.
+    ///
+    /// [`Arena`]: super::arena::Arena
+    pub unsafe fn into_arena(self) -> Arena<T, Options> {
+        // SAFETY: Checked by caller.
+        unsafe { Arena::from_manually_drop(self) }
+    }
+
+    /// Returns the items in the chunk at index `index` (i.e., the items
+    /// stored in the `index`-th chunk of memory allocated by this arena), or
+    /// [`None`] if the arena has fewer than `index + 1` chunks that contain
+    /// items.
+    ///
+    /// This method runs in O(`index`) time.
+    pub fn chunk<'a>(&self, index: usize) -> Option<&'a [T]>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        let chunk = self.chunk_ptrs().nth(index)?;
+        // SAFETY: `Self::chunk_ptrs` returns pointers to initialized items,
+        // and because `Options::Mutable` is false, no mutable references to
+        // them can exist.
+        Some(unsafe { &*chunk })
+    }
+
+    /// Calls `f` once for each chunk that contains items, in order, passing
+    /// the items in that chunk.
+    pub fn visit_chunks<F>(&self, f: F)
+    where
+        F: FnMut(&[T]),
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        self.chunk_slices().for_each(f);
+    }
+
+    /// Calls `f` once for each chunk that contains items, in order, passing
+    /// mutable references to the items in that chunk.
+    ///
+    /// # Safety
+    ///
+    /// There must be no references to items (or parts of items) in this arena
+    /// or instances of [`Iter`] or [`IterMut`] for this arena.
+    pub unsafe fn visit_chunks_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut [T]),
+    {
+        for chunk in self.chunk_ptrs() {
+            // SAFETY: `Self::chunk_ptrs` returns pointers to initialized
+            // items, and the caller guarantees that no other references to
+            // them exist.
+            f(unsafe { &mut *chunk });
+        }
+    }
+
+    /// Returns an iterator over mutable slices of the items in each chunk
+    /// that contains items, in order. Every yielded slice is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// There must be no references to items (or parts of items) in this arena
+    /// or instances of [`Iter`] or [`IterMut`] for this arena.
+    pub unsafe fn chunks_mut_unchecked<'a>(
+        &mut self,
+    ) -> impl Iterator<Item = &'a mut [T]> + '_
+    where
+        T: 'a,
+    {
+        self.chunk_ptrs().map(|chunk| {
+            // SAFETY: `Self::chunk_ptrs` returns pointers to initialized
+            // items in distinct chunks, and the caller guarantees that no
+            // other references to them exist.
+            unsafe { &mut *chunk }
+        })
+    }
+
+    /// Checks whether all of the items in the arena are stored contiguously,
+    /// i.e., whether they are all in a single chunk. This is the case if there
+    /// are at most `CHUNK_SIZE` items.
+    pub const fn is_contiguous(&self) -> bool {
+        self.len <= Self::CHUNK_SIZE
+    }
+
+    /// Returns all of the items in the arena as a slice, or [`None`] if the
+    /// items span more than one chunk (i.e., if there are more than
+    /// `CHUNK_SIZE` items).
+    pub fn as_slice<'a>(&self) -> Option<&'a [T]>
+    where
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        if !self.is_contiguous() {
+            return None;
+        }
+        Some(self.chunk(0).unwrap_or(&[]))
+    }
+
+    /// Returns the minimum item in the arena, or [`None`] if the arena is
+    /// empty. If several items are equally minimum, the first is returned.
+    ///
+    /// This is equivalent to `self.iter().min()`, but processes the items a
+    /// chunk at a time.
+    pub fn min<'a>(&self) -> Option<&'a T>
+    where
+        T: Ord,
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        self.chunk_slices().filter_map(|chunk| chunk.iter().min()).reduce(
+            |best, item| {
+                if item < best {
+                    item
+                } else {
+                    best
+                }
+            },
+        )
+    }
+
+    /// Returns the maximum item in the arena, or [`None`] if the arena is
+    /// empty. If several items are equally maximum, the last is returned.
+    ///
+    /// This is equivalent to `self.iter().max()`, but processes the items a
+    /// chunk at a time.
+    pub fn max<'a>(&self) -> Option<&'a T>
+    where
+        T: Ord,
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        self.chunk_slices().filter_map(|chunk| chunk.iter().max()).reduce(
+            |best, item| {
+                if item >= best {
+                    item
+                } else {
+                    best
+                }
+            },
+        )
+    }
+
+    /// Returns an iterator over the items in each chunk that contains items.
+    fn chunk_slices<'a>(&self) -> impl Iterator<Item = &'a [T]> + '_
+    where
+        T: 'a,
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        self.chunk_ptrs().map(|chunk| {
+            // SAFETY: `Self::chunk_ptrs` returns pointers to initialized
+            // items, and because `Options::Mutable` is false, no mutable
+            // references to them can exist.
+            unsafe { &*chunk }
+        })
+    }
+
+    /// Copies items from the arena, in order, into `dst`, stopping when
+    /// either `dst` is full or every item has been copied. Returns the number
+    /// of items copied.
+    ///
+    /// Each chunk is copied in a single operation, so this is faster than
+    /// copying items one at a time with [`Self::iter`].
+    pub fn copy_to_slice(&self, dst: &mut [T]) -> usize
+    where
+        T: Copy,
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        let mut copied = 0;
+        for (start, len) in self.chunk_starts() {
+            let count = len.min(dst.len() - copied);
+            if count == 0 {
+                break;
+            }
+            // SAFETY: `Self::chunk_starts` returns pointers to `len`
+            // initialized items, which can't be mutably borrowed because
+            // `Options::Mutable` is false, so they can't overlap with `dst`.
+            // `copied + count <= dst.len()`.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    start,
+                    dst.as_mut_ptr().add(copied),
+                    count,
+                );
+            }
+            copied += count;
+        }
+        copied
+    }
+
+    /// Returns an iterator over the byte ranges of the storage of every chunk
+    /// allocated by this arena, including chunks that don't yet contain any
+    /// items (see [`Self::reserve`]).
+    ///
+    /// This can be used to annotate the arena's memory for tools like
+    /// sanitizers or to scan it for roots. The pointers must not be used to
+    /// access items in ways that would conflict with existing references.
+    ///
+    /// If `T` is zero-sized, chunks have no item storage, so every range is
+    /// empty.
+    pub fn chunk_ranges(&self) -> impl Iterator<Item = Range<*const u8>> + '_ {
+        successors(self.head.clone(), ChunkRef::next).map(|chunk| {
+            if mem::size_of::<T>() == 0 {
+                // Item pointers for zero-sized types are dangling, so they
+                // can't be used as bounds.
+                let start = chunk.as_ptr().as_ptr() as *const u8;
+                return start..start;
+            }
+            // SAFETY: 0 and `Self::CHUNK_SIZE` are always less than or equal
+            // to the chunk capacity.
+            let (start, end) =
+                unsafe { (chunk.get(0), chunk.get(Self::CHUNK_SIZE)) };
+            start.as_ptr() as *const u8..end.as_ptr() as *const u8
+        })
+    }
+
+    /// Creates a new arena containing clones of the items in `a` and `b`,
+    /// merged in the order given by `cmp`. Both arenas must already be sorted
+    /// according to `cmp`. The merge is stable: when items compare equal,
+    /// items from `a` come first.
+    ///
+    /// Space for `a.len() + b.len()` items is reserved up front (see
+    /// [`Self::reserve`]).
+    pub fn merge_sorted<F>(a: &Self, b: &Self, mut cmp: F) -> Self
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+        Options: ArenaOptions<T, Mutable = Bool<false>>,
+    {
+        let mut arena = Self::new();
+        arena.reserve(a.len() + b.len());
+
+        let mut a = a.iter().peekable();
+        let mut b = b.iter().peekable();
+        loop {
+            let item = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) if cmp(y, x) == Ordering::Less => b.next(),
+                (Some(_), _) => a.next(),
+                (None, _) => b.next(),
+            };
+            let item = if let Some(item) = item {
+                item
+            } else {
+                break;
+            };
+            arena.alloc_shared(item.clone());
+        }
+        arena
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ManuallyDropArena<T> {
+    /// Creates a new arena containing the items in `array`, in order.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    fn from(array: [T; N]) -> Self {
+        let array = mem::ManuallyDrop::new(array);
+        // SAFETY: `array` contains `N` initialized items, and wrapping it in
+        // `ManuallyDrop` ensures they won't be dropped again.
+        unsafe { Self::move_from(array.as_ptr(), N) }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, Options> Deserialize<'de> for ManuallyDropArena<T, Options>
+where
+    T: Deserialize<'de>,
+    Options: ArenaOptions<T>,
+{
+    /// Deserializes a sequence, allocating each item in order. If
+    /// deserialization fails, the items deserialized so far are dropped.
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        Arena::deserialize(deserializer).map(Arena::into_manually_drop)
+    }
+}
+
+impl<T, Options: ArenaOptions<T>> FromIterator<T>
+    for ManuallyDropArena<T, Options>
+{
+    /// Creates a new arena containing the items in `iter`, in order.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut arena = Self::new();
+        arena.collect_from(iter);
+        arena
+    }
+}
+
+impl<T, Options: ArenaOptions<T>> Extend<T> for ManuallyDropArena<T, Options> {
+    /// Allocates every item in `iter`. See [`Self::collect_from`].
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.collect_from(iter);
+    }
 }
 
 impl<T, Options> ManuallyDropArena<T, Options>
@@ -394,11 +1888,103 @@ where
             chunk: chunk.or_else(|| self.head.clone()),
             index: position.index,
             end: self.end(),
+            offset: position.offset,
+            remaining: self.len - position.offset,
+            back: None,
             rc: self.rc.clone(),
             phantom: PhantomData,
         }
     }
 
+    /// Returns a [`Position`] referring to the item at index `index`, or
+    /// [`None`] if `index` is greater than the number of items. If `index`
+    /// is equal to the number of items, the position refers to the end of
+    /// the arena.
+    ///
+    /// This method runs in O(`index / CHUNK_SIZE`) time.
+    pub fn position_at_index(&self, index: usize) -> Option<Position> {
+        if index > self.len {
+            return None;
+        }
+        let mut chunk = self.head.clone();
+        let mut chunk_offset = index;
+        // Refer to the end of a chunk rather than the start of the next one,
+        // which may not exist yet.
+        while let Some(current) = &chunk {
+            let usable = current.usable();
+            if chunk_offset <= usable {
+                break;
+            }
+            chunk_offset -= usable;
+            chunk = current.next();
+        }
+        Some(Position {
+            chunk: chunk.as_ref().map(ChunkRef::as_ptr),
+            index: chunk_offset,
+            offset: index,
+            rc: self.rc.clone(),
+        })
+    }
+
+    fn alloc_ptr_with_position(&mut self, value: T) -> (NonNull<T>, Position) {
+        let item = self.alloc_ptr(value);
+        let position = Position {
+            chunk: self.tail.as_ref().map(ChunkRef::as_ptr),
+            index: self.tail_len - 1,
+            offset: self.len - 1,
+            rc: self.rc.clone(),
+        };
+        (item, position)
+    }
+
+    /// Like [`Self::alloc`], but also returns a [`Position`] referring to the
+    /// allocated item.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn alloc_with_position<'a>(
+        &mut self,
+        value: T,
+    ) -> (&'a mut T, Position)
+    where
+        Options:
+            'a + ArenaOptions<T, Mutable = Bool<true>, Pinned = Bool<false>>,
+    {
+        let (mut item, position) = self.alloc_ptr_with_position(value);
+        // SAFETY: See `Self::alloc`.
+        (unsafe { item.as_mut() }, position)
+    }
+
+    /// Returns the number of items between positions `a` and `b`, i.e., the
+    /// absolute difference of the indices they refer to. A position at the
+    /// end of the arena refers to index [`Self::len`].
+    ///
+    /// This method runs in constant time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` does not refer to a position in this arena.
+    pub fn position_distance(&self, a: &Position, b: &Position) -> usize {
+        for position in [a, b] {
+            assert!(
+                valid_rc_update(&position.rc, &self.rc),
+                "`position` is not part of this arena",
+            );
+        }
+        a.offset.abs_diff(b.offset)
+    }
+
+    /// Returns a pointer to the item at the specified position, or [`None`] if
+    /// the position is at the end of the arena.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `position` does not refer to a position in this arena.
+    pub(crate) fn ptr_at(&self, position: &Position) -> Option<NonNull<T>> {
+        self.iter_ptr_at(position).next()
+    }
+
     /// Returns an iterator starting at the specified position.
     ///
     /// # Panics
@@ -430,6 +2016,31 @@ where
         }
     }
 
+    /// Returns an iterator over pointers to the items in this arena, starting
+    /// at the specified position.
+    ///
+    /// Unlike [`Self::iter_at_unchecked`], the returned iterator doesn't
+    /// create any references to items, so the pointers can be used to build
+    /// custom traversals. They are non-null, properly aligned, and point to
+    /// initialized items, but it is up to the caller to dereference them in
+    /// a way that doesn't conflict with other references to the same items.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `position` does not refer to a position in this arena.
+    ///
+    /// # Safety
+    ///
+    /// The returned iterator counts as an instance of [`Iter`] for the
+    /// purposes of methods like [`Self::drop`] and [`Self::set_len`]: it must
+    /// not be used after any of them is called.
+    pub unsafe fn iter_ptr_at_raw(
+        &self,
+        position: &Position,
+    ) -> impl Iterator<Item = NonNull<T>> + Clone {
+        self.iter_ptr_at(position)
+    }
+
     /// Returns a mutable iterator starting at the specified position.
     ///
     /// # Panics
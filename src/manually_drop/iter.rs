@@ -19,13 +19,16 @@
 
 use super::ManuallyDropArena;
 use super::{ArenaChunk, ArenaRc};
-use crate::chunk::ChunkRef;
+use crate::chunk::{item_stride, ChunkRef};
+use crate::options::AllocatorPriv;
 use crate::ArenaOptions;
 use alloc::boxed::Box;
 use alloc::sync::Arc;
-use core::iter::FusedIterator;
+use core::cmp::Ordering;
+use core::iter::{self, FusedIterator};
 use core::marker::PhantomData;
-use core::ptr::NonNull;
+use core::mem;
+use core::ptr::{self, NonNull};
 use integral_constant::Bool;
 
 /// A position in an arena.
@@ -38,6 +41,8 @@ use integral_constant::Bool;
 pub struct Position {
     pub(super) chunk: Option<NonNull<()>>,
     pub(super) index: usize,
+    /// The index of the item in the arena.
+    pub(super) offset: usize,
     pub(super) rc: Option<Arc<()>>,
 }
 
@@ -51,14 +56,56 @@ unsafe impl Send for Position {}
 // field), so the position itself can be made `Sync`.
 unsafe impl Sync for Position {}
 
+impl Position {
+    /// Checks whether `self` and `other` belong to the same arena. Positions
+    /// without a reference count refer to the start of an arena that had no
+    /// chunks, and are only considered to belong to the same arena as each
+    /// other, so that equality stays transitive.
+    fn same_arena(&self, other: &Self) -> bool {
+        match (&self.rc, &other.rc) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Positions are equal if they belong to the same arena and refer to the same
+/// index in it. A position obtained before the arena allocated its first chunk
+/// isn't equal to any position obtained afterward.
+impl PartialEq for Position {
+    fn eq(&self, other: &Self) -> bool {
+        self.same_arena(other) && self.offset == other.offset
+    }
+}
+
+impl Eq for Position {}
+
+/// Positions in the same arena are ordered by the index they refer to.
+/// Comparing positions from different arenas is not meaningful, and returns
+/// [`None`] (see also [`PartialEq`]). For this reason, [`Position`] doesn't
+/// implement [`Ord`]; to sort positions from a single arena, use
+/// [`slice::sort_by`] with [`Self::partial_cmp`].
+impl PartialOrd for Position {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.same_arena(other).then(|| self.offset.cmp(&other.offset))
+    }
+}
+
 // Invariants:
 //
 // * All items in the list of chunks pointed to by `chunk` are initialized
 //   until `end` is reached. `end` marks the *exclusive* end of the range of
 //   initialized items.
 // * If `DROP` is true, `chunk` is the only `ChunkRef` that refers to any chunk
-//   in the corresponding arena.
-// * `index` is always less than or equal to the chunk capacity.
+//   in the corresponding arena, and it owns every chunk after it in the list.
+// * `index` is always less than or equal to the number of usable slots in
+//   `chunk` (see `ChunkRef::usable`).
+// * `offset` is the index in the arena of the next item to yield, and
+//   `remaining` is the number of items left to yield.
+// * If `back` is `Some((chunk, base))`, `chunk` is in the list of chunks
+//   pointed to by `chunk`, and `base` is the index in the arena of its first
+//   item.
 pub(super) struct IterPtr<
     T,
     Options: ArenaOptions<T>,
@@ -67,6 +114,12 @@ pub(super) struct IterPtr<
     pub chunk: Option<ArenaChunk<T, Options>>,
     pub index: usize,
     pub end: *const T,
+    pub offset: usize,
+    pub remaining: usize,
+    /// The chunk that the last item yielded by [`Self::next_back`] is in,
+    /// along with the index of its first item, so that it can be reused by
+    /// the next call.
+    pub back: Option<(ArenaChunk<T, Options>, usize)>,
     pub rc: Option<ArenaRc<T, Options>>,
     pub phantom: PhantomData<Box<T>>,
 }
@@ -80,6 +133,9 @@ where
             chunk: self.chunk.clone(),
             index: self.index,
             end: self.end,
+            offset: self.offset,
+            remaining: self.remaining,
+            back: self.back.clone(),
             rc: self.rc.clone(),
             phantom: self.phantom,
         }
@@ -93,19 +149,35 @@ where
     type Item = NonNull<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let chunk = self.chunk.clone()?;
-        // SAFETY: `self.index` is always less than or equal to the chunk
-        // capacity.
-        let mut item = unsafe { chunk.get(self.index) };
-        let end = self.end == item.as_ptr();
-
-        if end || self.index >= ArenaChunk::<T, Options>::CAPACITY {
-            let next = (!end).then(|| chunk.next()).flatten();
-            if DROP || next.is_some() {
-                self.index = 0;
-                self.chunk = next.clone();
+        loop {
+            let chunk = self.chunk.clone()?;
+            // SAFETY: `self.index` is always less than or equal to the chunk
+            // capacity.
+            let item = unsafe { chunk.get(self.index) };
+
+            if self.end == item.as_ptr() {
+                if DROP {
+                    self.chunk = None;
+                    // SAFETY: This type's invariants guarantee no other
+                    // `ChunkRef`s referring to chunks in this arena exist.
+                    unsafe {
+                        dealloc_chunks(chunk);
+                    }
+                }
+                return None;
+            }
+
+            if self.index < chunk.usable() {
+                self.index += 1;
+                self.consume(1);
+                return Some(item);
             }
 
+            // All chunks before the one containing `self.end` are full (up to
+            // their usable slots), so the next item is at the start of the
+            // next chunk.
+            self.chunk = chunk.next();
+            self.index = 0;
             if DROP {
                 // SAFETY: This type's invariants guarantee no other
                 // `ChunkRef`s referring to chunks in this arena exist.
@@ -113,17 +185,145 @@ where
                     chunk.dealloc();
                 }
             }
+        }
+    }
 
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if DROP {
+            // Skipped items must be dropped, so step through them one by one.
+            for _ in 0..n {
+                let item = self.next()?;
+                // SAFETY: This type yields initialized, properly aligned
+                // pointers, and because `DROP` is true, it owns the items.
+                unsafe {
+                    item.as_ptr().drop_in_place();
+                }
+            }
+            return self.next();
+        }
+
+        // Skip whole chunks at a time.
+        loop {
+            let chunk = self.chunk.clone()?;
             // SAFETY: `self.index` is always less than or equal to the chunk
             // capacity.
-            item = unsafe { next?.get(self.index) };
+            let (len, last) =
+                unsafe { chunk_len(&chunk, self.index, self.end) };
+            if n < len {
+                self.index += n;
+                self.consume(n);
+                return self.next();
+            }
+            self.index += len;
+            self.consume(len);
+            if last {
+                return None;
+            }
+            n -= len;
+            self.chunk = chunk.next();
+            self.index = 0;
         }
+    }
 
-        self.index += 1;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn count(self) -> usize {
+        // If `DROP` is true, any remaining items are dropped when `self` is.
+        self.remaining
+    }
+}
+
+impl<T, Options, const DROP: bool> DoubleEndedIterator
+    for IterPtr<T, Options, DROP>
+where
+    Options: ArenaOptions<T>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let target = self.offset + self.remaining - 1;
+        let (chunk, base) = match self.back.take() {
+            Some((chunk, base)) if target >= base => (chunk, base),
+            _ => {
+                // The list of chunks is singly linked, so find the previous
+                // chunk by walking forward from the front of the iterator.
+                let mut chunk = self.chunk.clone()?;
+                let mut base = self.offset - self.index;
+                while target - base >= chunk.usable() {
+                    base += chunk.usable();
+                    chunk = chunk.next().unwrap_or_else(|| {
+                        unreachable!("iterator should contain the item");
+                    });
+                }
+                (chunk, base)
+            }
+        };
+
+        // SAFETY: `target` is in `chunk`, so `target - base` is less than the
+        // number of usable slots in the chunk.
+        let item = unsafe { chunk.get(target - base) };
+        // Forward iteration now stops before `item`.
+        self.end = item.as_ptr();
+        self.remaining -= 1;
+        self.back = Some((chunk, base));
         Some(item)
     }
 }
 
+/// Returns the number of initialized items in `chunk` starting at index
+/// `index`, where `end` is the exclusive end of the initialized items in the
+/// list of chunks (see [`IterPtr`]). Also returns whether the initialized
+/// items end in `chunk`.
+///
+/// # Safety
+///
+/// `index` must be less than or equal to the number of usable slots in the
+/// chunk (see [`ChunkRef::usable`]).
+unsafe fn chunk_len<T, Array, A: AllocatorPriv>(
+    chunk: &ChunkRef<T, Array, A>,
+    index: usize,
+    end: *const T,
+) -> (usize, bool) {
+    // SAFETY: Checked by caller. The number of usable slots is always less
+    // than or equal to the chunk capacity.
+    let (start, chunk_end) =
+        unsafe { (chunk.get(index), chunk.get(chunk.usable())) };
+    let start = start.as_ptr() as usize;
+    let chunk_end = chunk_end.as_ptr() as usize;
+    let end = end as usize;
+
+    // Chunks never overlap, so if `end` lies within the rest of this chunk,
+    // the initialized items end in this chunk.
+    let last = (start..=chunk_end).contains(&end);
+    let stop = if last {
+        end
+    } else {
+        chunk_end
+    };
+    ((stop - start) / item_stride::<T>(), last)
+}
+
+/// Frees `chunk` and all chunks after it.
+///
+/// # Safety
+///
+/// No other [`ChunkRef`]s may refer to any of the chunks.
+unsafe fn dealloc_chunks<T, Array, A: AllocatorPriv>(
+    chunk: ChunkRef<T, Array, A>,
+) {
+    let mut chunk = Some(chunk);
+    while let Some(current) = chunk {
+        chunk = current.next();
+        // SAFETY: Checked by caller.
+        unsafe {
+            current.dealloc();
+        }
+    }
+}
+
 #[rustfmt::skip]
 impl<T, Options, const DROP: bool> FusedIterator for IterPtr<T, Options, DROP>
 where
@@ -131,6 +331,63 @@ where
 {
 }
 
+impl<T, Options, const DROP: bool> IterPtr<T, Options, DROP>
+where
+    Options: ArenaOptions<T>,
+{
+    /// Records that `n` more items have been yielded or skipped.
+    fn consume(&mut self, n: usize) {
+        self.offset += n;
+        self.remaining -= n;
+    }
+
+    /// Returns the item that the next call to [`Self::next`] would return,
+    /// without advancing the iterator.
+    pub fn peek(&self) -> Option<NonNull<T>> {
+        let chunk = self.chunk.as_ref()?;
+
+        // SAFETY: `self.index` is always less than or equal to the chunk
+        // capacity.
+        let item = unsafe { chunk.get(self.index) };
+        if self.end == item.as_ptr() {
+            return None;
+        }
+        if self.index < chunk.usable() {
+            return Some(item);
+        }
+        // SAFETY: 0 is always less than or equal to the chunk capacity.
+        let item = unsafe { chunk.next()?.get(0) };
+        (self.end != item.as_ptr()).then(|| item)
+    }
+
+    /// Checks whether the next call to [`Self::next`] would return [`None`].
+    pub fn is_at_end(&self) -> bool {
+        self.peek().is_none()
+    }
+
+    /// Returns an iterator over the items that haven't been yielded yet,
+    /// grouped by chunk. Every yielded slice is non-empty.
+    pub fn remaining_chunks(&self) -> impl Iterator<Item = *mut [T]> {
+        let mut chunk = self.chunk.clone();
+        let mut index = self.index;
+        let end = self.end;
+        iter::from_fn(move || {
+            let current = chunk.take()?;
+            // SAFETY: `index` is always less than or equal to the chunk
+            // capacity.
+            let (start, (len, last)) = unsafe {
+                (current.get(index).as_ptr(), chunk_len(&current, index, end))
+            };
+            if !last {
+                chunk = current.next();
+                index = 0;
+            }
+            Some((len > 0).then(|| ptr::slice_from_raw_parts_mut(start, len)))
+        })
+        .flatten()
+    }
+}
+
 impl<T, Options, const DROP: bool> IterPtr<T, Options, DROP>
 where
     Options: ArenaOptions<T, SupportsPositions = Bool<true>>,
@@ -142,6 +399,7 @@ where
         Position {
             chunk: self.chunk.as_ref().map(ChunkRef::as_ptr),
             index: self.index,
+            offset: self.offset,
             rc: self.rc.clone(),
         }
     }
@@ -168,6 +426,17 @@ where
         if !DROP {
             return;
         }
+        if !mem::needs_drop::<T>() {
+            // The items don't need to be dropped, so just free the chunks.
+            if let Some(chunk) = self.chunk.take() {
+                // SAFETY: This type's invariants guarantee no other
+                // `ChunkRef`s referring to chunks in this arena exist.
+                unsafe {
+                    dealloc_chunks(chunk);
+                }
+            }
+            return;
+        }
         for item in self {
             // SAFETY: This type yields initialized, properly aligned
             // pointers.
@@ -196,6 +465,47 @@ where
     pub fn as_position(&self) -> Position {
         self.inner.as_position()
     }
+
+    /// Advances this iterator past items for which `pred` returns true, and
+    /// returns a [`Position`] referring to the first item for which `pred`
+    /// returns false. That item is not consumed: the iterator is left
+    /// positioned at it, so the next call to [`Iterator::next`] returns it.
+    ///
+    /// If `pred` returns true for every remaining item, the iterator is
+    /// exhausted and [`None`] is returned.
+    pub fn skip_to<F>(&mut self, mut pred: F) -> Option<Position>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        while let Some(item) = self.inner.peek() {
+            // SAFETY: `IterPtr` always returns initialized, properly aligned
+            // pointers.
+            if !pred(unsafe { item.as_ref() }) {
+                return Some(self.as_position());
+            }
+            self.inner.next();
+        }
+        None
+    }
+}
+
+impl<'a, T, Options: ArenaOptions<T>> Iter<'a, T, Options> {
+    /// Checks whether this iterator has reached the end, i.e., whether the
+    /// next call to [`Iterator::next`] will return [`None`].
+    pub fn is_at_end(&self) -> bool {
+        self.inner.is_at_end()
+    }
+
+    /// Returns an iterator over the items that this iterator hasn't yielded
+    /// yet, grouped into slices by chunk. This iterator is not advanced.
+    pub fn remaining_chunks(&self) -> impl Iterator<Item = &'a [T]> {
+        self.inner.remaining_chunks().map(|chunk| {
+            // SAFETY: `IterPtr::remaining_chunks` returns pointers to
+            // initialized items, which this type is allowed to borrow
+            // immutably for `'a`.
+            unsafe { &*chunk }
+        })
+    }
 }
 
 impl<'a, T, Options: ArenaOptions<T>> Iterator for Iter<'a, T, Options> {
@@ -206,10 +516,40 @@ impl<'a, T, Options: ArenaOptions<T>> Iterator for Iter<'a, T, Options> {
         // pointers.
         Some(unsafe { self.inner.next()?.as_ref() })
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // SAFETY: See `Self::next`.
+        Some(unsafe { self.inner.nth(n)?.as_ref() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<T, Options> DoubleEndedIterator for Iter<'_, T, Options>
+where
+    Options: ArenaOptions<T>,
+{
+    /// Returns the last item that hasn't been yielded yet.
+    ///
+    /// This runs in O(1) time, except when moving to the previous chunk,
+    /// which runs in O(`len / CHUNK_SIZE`) time (chunks are singly linked).
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: `IterPtr` always returns initialized, properly aligned
+        // pointers, and never yields the same item from both ends.
+        Some(unsafe { self.inner.next_back()?.as_ref() })
+    }
 }
 
 impl<T, Options: ArenaOptions<T>> FusedIterator for Iter<'_, T, Options> {}
 
+impl<T, Options: ArenaOptions<T>> ExactSizeIterator for Iter<'_, T, Options> {}
+
 impl<T, Options: ArenaOptions<T>> Clone for Iter<'_, T, Options> {
     fn clone(&self) -> Self {
         Self {
@@ -271,6 +611,24 @@ where
     }
 }
 
+impl<T, Options: ArenaOptions<T>> IterMut<'_, T, Options> {
+    /// Checks whether this iterator has reached the end, i.e., whether the
+    /// next call to [`Iterator::next`] will return [`None`].
+    pub fn is_at_end(&self) -> bool {
+        self.inner.is_at_end()
+    }
+
+    /// Returns an immutable iterator over the items that this iterator hasn't
+    /// yielded yet. The returned iterator borrows this iterator, so this
+    /// iterator can't be advanced until the returned iterator is dropped.
+    pub fn as_iter(&self) -> Iter<'_, T, Options> {
+        Iter {
+            inner: self.inner.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
 impl<'a, T, Options: ArenaOptions<T>> Iterator for IterMut<'a, T, Options> {
     type Item = &'a mut T;
 
@@ -279,10 +637,43 @@ impl<'a, T, Options: ArenaOptions<T>> Iterator for IterMut<'a, T, Options> {
         // pointers.
         Some(unsafe { self.inner.next()?.as_mut() })
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // SAFETY: See `Self::next`.
+        Some(unsafe { self.inner.nth(n)?.as_mut() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<T, Options> DoubleEndedIterator for IterMut<'_, T, Options>
+where
+    Options: ArenaOptions<T>,
+{
+    /// Returns the last item that hasn't been yielded yet.
+    ///
+    /// This runs in O(1) time, except when moving to the previous chunk,
+    /// which runs in O(`len / CHUNK_SIZE`) time (chunks are singly linked).
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: `IterPtr` always returns initialized, properly aligned
+        // pointers, and never yields the same item from both ends.
+        Some(unsafe { self.inner.next_back()?.as_mut() })
+    }
 }
 
 impl<T, Options: ArenaOptions<T>> FusedIterator for IterMut<'_, T, Options> {}
 
+impl<T, Options: ArenaOptions<T>> ExactSizeIterator
+    for IterMut<'_, T, Options>
+{
+}
+
 // SAFETY: This type yields mutable references to items in the arena, so it
 // can be `Send` as long as `T` is `Send`. `T` doesn't need to be `Sync`
 // because no other iterator that yields items from the arena can exist at the
@@ -294,9 +685,25 @@ where
 {
 }
 
-// SAFETY: This type has no `&self` methods that access shared data or fields
-// with non-`Sync` interior mutability.
-unsafe impl<T, Options: ArenaOptions<T>> Sync for IterMut<'_, T, Options> {}
+/// [`IterMut::as_iter`] yields shared references to the remaining items, so
+/// this type is `Sync` only if `T` is:
+///
+/// ```compile_fail
+/// # use fixed_typed_arena::Arena;
+/// use std::cell::Cell;
+/// fn assert_sync<T: Sync>(_: &T) {}
+/// let mut arena = Arena::<Cell<u32>>::new();
+/// assert_sync(&arena.iter_mut()); // Error: `Cell<u32>` is not `Sync`
+/// ```
+// SAFETY: The only `&self` method that accesses items is `Self::as_iter`,
+// which yields shared references to them. Sharing `&Self` across threads thus
+// shares `&T` across threads, which is sound when `T` is `Sync`.
+unsafe impl<T, Options> Sync for IterMut<'_, T, Options>
+where
+    T: Sync,
+    Options: ArenaOptions<T>,
+{
+}
 
 /// An owning iterator over the items in an arena.
 pub struct IntoIter<T, Options: ArenaOptions<T>>(
@@ -310,10 +717,34 @@ impl<T, Options: ArenaOptions<T>> Iterator for IntoIter<T, Options> {
         // SAFETY: `IterPtr` yields initialized, properly aligned pointers.
         Some(unsafe { self.0.next()?.as_ptr().read() })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.0.count()
+    }
+}
+
+impl<T, Options> DoubleEndedIterator for IntoIter<T, Options>
+where
+    Options: ArenaOptions<T>,
+{
+    /// Returns the last item that hasn't been yielded yet. See
+    /// [`Iter::next_back`].
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: `IterPtr` yields initialized, properly aligned pointers.
+        // Forward iteration (including dropping the remaining items) stops
+        // before the yielded item, so it won't be read or dropped again.
+        Some(unsafe { self.0.next_back()?.as_ptr().read() })
+    }
 }
 
 impl<T, Options: ArenaOptions<T>> FusedIterator for IntoIter<T, Options> {}
 
+impl<T, Options: ArenaOptions<T>> ExactSizeIterator for IntoIter<T, Options> {}
+
 // SAFETY: This type owns the items in the arena, so it can be `Send` as long
 // as `T` is `Send`.
 unsafe impl<T, Options> Send for IntoIter<T, Options>
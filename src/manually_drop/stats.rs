@@ -0,0 +1,56 @@
+/*
+ * Copyright (C) 2021-2022 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of fixed-typed-arena.
+ *
+ * fixed-typed-arena is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * fixed-typed-arena is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with fixed-typed-arena. If not, see <https://www.gnu.org/licenses/>.
+ */
+/// Cumulative allocation statistics for an arena.
+///
+/// Statistics are kept for the lifetime of the arena value: they are not
+/// reset when the arena's items are dropped (e.g., by
+/// [`ManuallyDropArena::drop`]), so they can be used to measure the peak
+/// usage of an arena that is reused for many workloads.
+///
+/// This type is available only with the crate feature `stats`.
+///
+/// [`ManuallyDropArena::drop`]: super::ManuallyDropArena::drop
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Stats {
+    /// The total number of items ever allocated.
+    pub total_items: u64,
+    /// The largest number of items the arena has held at once.
+    pub peak_len: usize,
+    /// The largest number of chunks the arena has held at once.
+    pub peak_chunks: usize,
+    /// The total number of chunks ever allocated.
+    pub chunk_allocations: u64,
+}
+
+impl Stats {
+    /// Records that `added` items were allocated, after which the arena
+    /// holds `len` items.
+    pub(super) fn record_items(&mut self, added: usize, len: usize) {
+        self.total_items += added as u64;
+        self.peak_len = self.peak_len.max(len);
+    }
+
+    /// Records that a chunk was allocated, after which the arena holds
+    /// `chunks` chunks.
+    pub(super) fn record_chunk(&mut self, chunks: usize) {
+        self.chunk_allocations += 1;
+        self.peak_chunks = self.peak_chunks.max(chunks);
+    }
+}
@@ -19,10 +19,13 @@
 
 //! Arena options.
 
+use alloc::alloc::Layout;
 use alloc::sync::Arc;
+use core::cell::Cell;
 use core::convert::Infallible;
 use core::marker::PhantomData;
-use core::mem::MaybeUninit;
+use core::mem::{self, MaybeUninit};
+use core::ptr::NonNull;
 
 /// Re-exported for backward compatibility.
 #[doc(hidden)]
@@ -35,10 +38,34 @@ mod detail {
 
     pub trait SupportsPositionsPriv {
         type Rc: Clone + Send + Sync;
+        /// The approximate size of the heap allocation made for `Self::Rc`.
+        const RC_ALLOC_SIZE: usize = 0;
         fn init_rc(_rc: &mut Option<Self::Rc>) {}
     }
 
     pub trait MutablePriv {}
+
+    pub trait MaxChunksPriv {
+        const MAX: usize;
+    }
+
+    pub trait TrackBorrowsPriv {
+        type Borrows: Default;
+        #[track_caller]
+        fn check(_borrows: &Self::Borrows) {}
+    }
+
+    pub trait PinnedPriv {}
+
+    pub trait AllocatorPriv {
+        fn allocate(layout: super::Layout) -> Option<super::NonNull<u8>>;
+
+        /// # Safety
+        ///
+        /// `ptr` must have been returned by [`Self::allocate`] with the same
+        /// `layout`, and must not have been deallocated already.
+        unsafe fn deallocate(ptr: super::NonNull<u8>, layout: super::Layout);
+    }
 }
 
 pub(crate) use detail::*;
@@ -64,6 +91,9 @@ impl SupportsPositionsPriv for Bool<false> {
 impl SupportsPositionsPriv for Bool<true> {
     type Rc = Arc<()>;
 
+    // `Arc` stores a strong and weak count alongside its (empty) value.
+    const RC_ALLOC_SIZE: usize = mem::size_of::<usize>() * 2;
+
     fn init_rc(rc: &mut Option<Self::Rc>) {
         rc.get_or_insert_with(Arc::default);
     }
@@ -76,10 +106,139 @@ impl Mutable for Bool<false> {}
 impl Mutable for Bool<true> {}
 impl<const B: bool> MutablePriv for Bool<B> {}
 
+/// Trait bound on [`ArenaOptions::MaxChunks`].
+pub trait MaxChunks: MaxChunksPriv {}
+
+impl<const N: usize> MaxChunks for Usize<N> {}
+impl<const N: usize> MaxChunksPriv for Usize<N> {
+    const MAX: usize = N;
+}
+
+/// Trait bound on [`ArenaOptions::TrackBorrows`].
+pub trait TrackBorrows: TrackBorrowsPriv {}
+
+impl TrackBorrows for Bool<false> {}
+impl TrackBorrows for Bool<true> {}
+
+impl TrackBorrowsPriv for Bool<false> {
+    type Borrows = ();
+}
+
+impl TrackBorrowsPriv for Bool<true> {
+    /// The number of live [`ArenaRefMut`] guards.
+    ///
+    /// [`ArenaRefMut`]: crate::arena::ArenaRefMut
+    type Borrows = Cell<usize>;
+
+    #[track_caller]
+    fn check(borrows: &Self::Borrows) {
+        assert!(
+            borrows.get() == 0,
+            "arena items are mutably borrowed by `ArenaRefMut` guards",
+        );
+    }
+}
+
+/// Trait bound on [`ArenaOptions::Pinned`].
+pub trait Pinned: PinnedPriv {}
+
+impl Pinned for Bool<false> {}
+impl Pinned for Bool<true> {}
+impl<const B: bool> PinnedPriv for Bool<B> {}
+
+/// Trait bound on [`ArenaOptions::Allocator`].
+pub trait Allocator: AllocatorPriv {}
+
+/// The global allocator, registered with `#[global_allocator]`. This is the
+/// default [`ArenaOptions::Allocator`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Global;
+
+impl Allocator for Global {}
+impl AllocatorPriv for Global {
+    fn allocate(layout: Layout) -> Option<NonNull<u8>> {
+        assert!(layout.size() > 0);
+        // SAFETY: We just checked that `layout` has non-zero size.
+        NonNull::new(unsafe { alloc::alloc::alloc(layout) })
+    }
+
+    unsafe fn deallocate(ptr: NonNull<u8>, layout: Layout) {
+        // SAFETY: Checked by caller.
+        unsafe { alloc::alloc::dealloc(ptr.as_ptr(), layout) }
+    }
+}
+
+/// Any zero-sized [`Allocator`](allocator_api2::alloc::Allocator) from
+/// [`allocator_api2`] can be used with the crate feature `allocator_api`.
+/// Allocators that aren't zero-sized, including by-reference allocators like
+/// `&Bump`, are not supported.
+#[cfg(feature = "allocator_api")]
+impl<A> Allocator for A where
+    A: allocator_api2::alloc::Allocator + Default + Send + Sync
+{
+}
+
+/// Fails to compile when `A` isn't zero-sized, once [`Self::ASSERT`] is
+/// referenced.
+#[cfg(feature = "allocator_api")]
+struct ZeroSized<A>(PhantomData<A>);
+
+#[cfg(feature = "allocator_api")]
+impl<A> ZeroSized<A> {
+    // The arena doesn't store the allocator, so every value must refer to the
+    // same allocator.
+    const ASSERT: () = assert!(
+        mem::size_of::<A>() == 0,
+        "arena allocators must be zero-sized",
+    );
+}
+
+#[cfg(feature = "allocator_api")]
+impl<A> AllocatorPriv for A
+where
+    A: allocator_api2::alloc::Allocator + Default + Send + Sync,
+{
+    fn allocate(layout: Layout) -> Option<NonNull<u8>> {
+        #[allow(clippy::let_unit_value)]
+        let () = ZeroSized::<A>::ASSERT;
+        A::default().allocate(layout).ok().map(NonNull::cast)
+    }
+
+    unsafe fn deallocate(ptr: NonNull<u8>, layout: Layout) {
+        // SAFETY: Checked by caller. `A` is zero-sized (checked at compile
+        // time in `Self::allocate`), so `A::default()` is the same allocator
+        // that allocated `ptr`.
+        unsafe { A::default().deallocate(ptr, layout) }
+    }
+}
+
 mod sealed {
     pub trait Sealed {}
 }
 
+/// Returns the number of items of type `T` that fit in `bytes` bytes, for use
+/// as a chunk size. The result is always at least 1.
+///
+/// This is useful for choosing a chunk size based on a memory budget:
+///
+/// ```
+/// use fixed_typed_arena::{chunk_size_for, Arena};
+/// struct Node([u64; 4]);
+///
+/// let arena = Arena::<Node, { chunk_size_for::<Node>(4096) }>::new();
+/// # arena.alloc(Node([0; 4]));
+/// ```
+///
+/// Note that each chunk also stores a pointer to the next chunk, so the size
+/// of each allocation will be slightly larger than `bytes`.
+pub const fn chunk_size_for<T>(bytes: usize) -> usize {
+    let size = mem::size_of::<T>();
+    if size == 0 || bytes < size {
+        return 1;
+    }
+    bytes / size
+}
+
 /// Arena options trait.
 ///
 /// This is a sealed trait; use the [`Options`] type, which implements this
@@ -87,16 +246,22 @@ mod sealed {
 pub trait ArenaOptions<T>: sealed::Sealed {
     /// The number of elements of type `T` that each chunk can hold.
     ///
+    /// This is ignored if `T` is zero-sized: such items don't occupy any
+    /// memory, so a single chunk can hold as many as the arena allows.
+    ///
     /// *Default:* 16
     type ChunkSize: ChunkSize<T>;
 
     /// If true, enables the use of [`Position`]s, allowing methods like
     /// [`IterMut::as_position`] and [`Arena::iter_mut_at`] to be called, at
-    /// the cost of using slightly more memory.
+    /// the cost of using slightly more memory (see
+    /// [`ManuallyDropArena::positions_overhead`]).
     ///
     /// *Default:* false
     ///
     /// [`Position`]: crate::iter::Position
+    /// [`ManuallyDropArena::positions_overhead`]:
+    ///     crate::manually_drop::ManuallyDropArena::positions_overhead
     /// [`IterMut::as_position`]: crate::iter::IterMut::as_position
     /// [`Arena::iter_mut_at`]: crate::arena::Arena::iter_mut_at
     type SupportsPositions: SupportsPositions;
@@ -105,6 +270,75 @@ pub trait ArenaOptions<T>: sealed::Sealed {
     ///
     /// *Default:* true
     type Mutable: Mutable;
+
+    /// The maximum number of chunks the arena may allocate. Once the arena
+    /// has allocated this many chunks and they are all full, allocation
+    /// methods that start with `try_` return [`None`], and other allocation
+    /// methods panic.
+    ///
+    /// *Default:* [`usize::MAX`] (unlimited)
+    type MaxChunks: MaxChunks;
+
+    /// If true, and [`Self::Mutable`] is false, mutable references to items
+    /// can be obtained through guards returned by [`Arena::alloc_tracked`].
+    /// The arena counts the live guards, and methods that provide immutable
+    /// access to existing items, like [`Arena::iter`], panic while any guard
+    /// is alive.
+    ///
+    /// *Default:* false
+    ///
+    /// [`Arena::alloc_tracked`]: crate::arena::Arena::alloc_tracked
+    /// [`Arena::iter`]: crate::arena::Arena::iter
+    type TrackBorrows: TrackBorrows;
+
+    /// If true, items in the arena are never moved or made available through
+    /// mutable references (which would allow them to be moved, e.g., with
+    /// [`mem::swap`]) until they are dropped. Safe methods that could move
+    /// items, like [`Arena::iter_mut`] and [`Arena::reorder_by_key`], are
+    /// unavailable.
+    ///
+    /// This upholds the [pinning] guarantee for every item in the arena, so
+    /// shared references to items can be wrapped in [`Pin`], and
+    /// [`Arena::alloc_pin`] can return pinned mutable references. Unsafe
+    /// methods that provide mutable access, like
+    /// [`ManuallyDropArena::iter_mut_unchecked`], are still available;
+    /// callers must not use them to move items.
+    ///
+    /// ```compile_fail
+    /// use fixed_typed_arena::Arena;
+    /// type PinnedArena<T> = Arena<T, 16, false, true, { usize::MAX }, false, true>;
+    /// let mut arena = PinnedArena::new();
+    /// arena.alloc_shared(1_u8);
+    /// arena.iter_mut(); // Error: `Pinned` is true
+    /// ```
+    ///
+    /// *Default:* false
+    ///
+    /// [`Arena::alloc_pin`]: crate::arena::Arena::alloc_pin
+    /// [`Arena::iter_mut`]: crate::arena::Arena::iter_mut
+    /// [`Arena::reorder_by_key`]: crate::arena::Arena::reorder_by_key
+    /// [`ManuallyDropArena::iter_mut_unchecked`]:
+    ///     crate::manually_drop::ManuallyDropArena::iter_mut_unchecked
+    /// [pinning]: core::pin
+    /// [`Pin`]: core::pin::Pin
+    type Pinned: Pinned;
+
+    /// The allocator used to allocate and free chunks.
+    ///
+    /// The arena doesn't store an allocator value; each time a chunk is
+    /// allocated or freed, a new value is created with [`Default`]. The
+    /// allocator must therefore be zero-sized, like a handle to an allocator
+    /// in a `static`. With the crate feature `allocator_api`, any such
+    /// `Allocator` from the `allocator-api2` crate can be used; using an
+    /// allocator that isn't zero-sized is a compile-time error.
+    ///
+    /// By-reference allocators, like `&Bump` from `bumpalo`, are not
+    /// supported.
+    ///
+    /// This can only be changed with [`TypedOptions`].
+    ///
+    /// *Default:* [`Global`]
+    type Allocator: Allocator;
 }
 
 /// Arena options.
@@ -118,15 +352,27 @@ pub trait ArenaOptions<T>: sealed::Sealed {
 /// `CHUNK_SIZE`         | [`ArenaOptions::ChunkSize`]
 /// `SUPPORTS_POSITIONS` | [`ArenaOptions::SupportsPositions`]
 /// `MUTABLE`            | [`ArenaOptions::Mutable`]
+/// `MAX_CHUNKS`         | [`ArenaOptions::MaxChunks`]
+/// `TRACK_BORROWS`      | [`ArenaOptions::TrackBorrows`]
+/// `PINNED`             | [`ArenaOptions::Pinned`]
+///
+/// [`ArenaOptions::Allocator`] is always [`Global`]; use [`TypedOptions`] to
+/// change it.
 #[rustfmt::skip]
 pub type Options<
     const CHUNK_SIZE: usize = 16,
     const SUPPORTS_POSITIONS: bool = false,
     const MUTABLE: bool = true,
+    const MAX_CHUNKS: usize = { usize::MAX },
+    const TRACK_BORROWS: bool = false,
+    const PINNED: bool = false,
 > = TypedOptions<
     Usize<CHUNK_SIZE>,
     Bool<SUPPORTS_POSITIONS>,
     Bool<MUTABLE>,
+    Usize<MAX_CHUNKS>,
+    Bool<TRACK_BORROWS>,
+    Bool<PINNED>,
 >;
 
 /// Like [`Options`], but uses types instead of const parameters.
@@ -138,10 +384,18 @@ pub struct TypedOptions<
     ChunkSize = Usize<16>,
     SupportsPositions = Bool<false>,
     Mutable = Bool<true>,
+    MaxChunks = Usize<{ usize::MAX }>,
+    TrackBorrows = Bool<false>,
+    Pinned = Bool<false>,
+    Allocator = Global,
 >(PhantomData<fn() -> (
     ChunkSize,
     SupportsPositions,
     Mutable,
+    MaxChunks,
+    TrackBorrows,
+    Pinned,
+    Allocator,
 )>);
 
 #[rustfmt::skip]
@@ -149,10 +403,18 @@ impl<
     ChunkSize,
     SupportsPositions,
     Mutable,
+    MaxChunks,
+    TrackBorrows,
+    Pinned,
+    Allocator,
 > sealed::Sealed for TypedOptions<
     ChunkSize,
     SupportsPositions,
     Mutable,
+    MaxChunks,
+    TrackBorrows,
+    Pinned,
+    Allocator,
 > {}
 
 #[rustfmt::skip]
@@ -161,12 +423,24 @@ impl<
     ChunkSize: self::ChunkSize<T>,
     SupportsPositions: self::SupportsPositions,
     Mutable: self::Mutable,
+    MaxChunks: self::MaxChunks,
+    TrackBorrows: self::TrackBorrows,
+    Pinned: self::Pinned,
+    Allocator: self::Allocator,
 > ArenaOptions<T> for TypedOptions<
     ChunkSize,
     SupportsPositions,
     Mutable,
+    MaxChunks,
+    TrackBorrows,
+    Pinned,
+    Allocator,
 > {
     type ChunkSize = ChunkSize;
     type SupportsPositions = SupportsPositions;
     type Mutable = Mutable;
+    type MaxChunks = MaxChunks;
+    type TrackBorrows = TrackBorrows;
+    type Pinned = Pinned;
+    type Allocator = Allocator;
 }
@@ -0,0 +1,168 @@
+/*
+ * Copyright (C) 2022 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of fixed-typed-arena.
+ *
+ * fixed-typed-arena is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * fixed-typed-arena is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with fixed-typed-arena. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! An arena that stores its items inline, without allocating memory.
+
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::slice;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// An arena that stores up to `N` items of type `T` inline.
+///
+/// Unlike [`Arena`], this type never allocates memory: its items are stored
+/// in a single fixed-size array within the arena itself, so it can be used
+/// on targets without a global allocator, e.g., by placing it in a `static`
+/// item. Once `N` items have been allocated, further allocations fail.
+///
+/// ```
+/// use fixed_typed_arena::static_arena::StaticArena;
+/// static ARENA: StaticArena<u32, 4> = StaticArena::new();
+///
+/// let item = ARENA.alloc(1).unwrap();
+/// *item += 1;
+/// ```
+///
+/// Items are dropped when the arena is dropped (items in a `static` are never
+/// dropped).
+///
+/// [`Arena`]: crate::arena::Arena
+pub struct StaticArena<T, const N: usize> {
+    items: UnsafeCell<MaybeUninit<[T; N]>>,
+    /// The number of slots that have been claimed by [`Self::alloc`]. When
+    /// `&mut self` is available, this is the number of initialized items.
+    len: AtomicUsize,
+}
+
+// SAFETY: `&self` methods never access items; `Self::alloc` claims each slot
+// with an atomic operation, so every slot is written and borrowed by only one
+// thread. Items can thus be moved into the arena from, and mutably borrowed
+// by, any thread, which is sound when `T` is `Send` (like `Mutex<T>`).
+unsafe impl<T: Send, const N: usize> Sync for StaticArena<T, N> {}
+
+impl<T, const N: usize> Default for StaticArena<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> StaticArena<T, N> {
+    /// Creates a new [`StaticArena`].
+    pub const fn new() -> Self {
+        Self {
+            items: UnsafeCell::new(MaybeUninit::uninit()),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the total number of items that have been allocated.
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Checks whether the arena is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of items the arena can hold (`N`).
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    fn as_ptr(&self) -> *mut T {
+        self.items.get().cast()
+    }
+
+    /// Allocates a new item in the arena and initializes it with `value`.
+    /// Returns a reference to the allocated item, or [`None`] (dropping
+    /// `value`) if the arena already contains `N` items.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc(&self, value: T) -> Option<&mut T> {
+        // No other memory is synchronized through `self.len`: items are only
+        // read through `&mut self`, which already implies synchronization.
+        let len = self
+            .len
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |len| {
+                (len < N).then(|| len + 1)
+            })
+            .ok()?;
+        // SAFETY: `len` is less than `N`, so the slot is within the array.
+        let item = unsafe { self.as_ptr().add(len) };
+        // SAFETY: We claimed the slot at index `len`, so it is uninitialized
+        // and no other thread or reference can access it.
+        unsafe {
+            item.write(value);
+        }
+        // SAFETY: We just initialized the item, and every call to this method
+        // claims a different slot, so this is the only reference to it.
+        Some(unsafe { &mut *item })
+    }
+
+    /// Returns a slice of the items in this arena, in the order they were
+    /// allocated.
+    ///
+    /// This method takes `&mut self` because [`Self::alloc`] returns mutable
+    /// references to the items, which must not be aliased.
+    pub fn as_slice(&mut self) -> &[T] {
+        self.as_mut_slice()
+    }
+
+    /// Returns a mutable slice of the items in this arena, in the order they
+    /// were allocated.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = *self.len.get_mut();
+        // SAFETY: `&mut self` guarantees no calls to `Self::alloc` are in
+        // progress, so the first `len` items are initialized, and no other
+        // references to them exist.
+        unsafe { slice::from_raw_parts_mut(self.as_ptr(), len) }
+    }
+
+    /// Returns an iterator over the items in this arena.
+    ///
+    /// Like [`Self::as_slice`], this method takes `&mut self` so that it
+    /// can't alias references returned by [`Self::alloc`].
+    pub fn iter(&mut self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns a mutable iterator over the items in this arena.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut StaticArena<T, N> {
+    type IntoIter = slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> Drop for StaticArena<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: The first `self.len` items are initialized and will not be
+        // accessed again.
+        unsafe {
+            ptr::drop_in_place(self.as_mut_slice());
+        }
+    }
+}
@@ -17,11 +17,14 @@
  * along with fixed-typed-arena. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use super::{live_allocations, silent_panic};
 use crate::manually_drop;
 use crate::{ArenaOptions, ManuallyDropArena};
 use alloc::rc::Rc;
 use alloc::vec::Vec;
 use core::cell::Cell;
+use core::cmp::Ordering;
+use std::panic::{self, AssertUnwindSafe};
 
 #[test]
 fn empty() {
@@ -80,6 +83,237 @@ fn iter() {
     }
 }
 
+#[test]
+fn chunk() {
+    type Arena<T> = ManuallyDropArena<
+        T,
+        4,     /* CHUNK_SIZE */
+        false, /* SUPPORTS_POSITIONS */
+        false, /* MUTABLE */
+    >;
+
+    let mut arena = Arena::new();
+    assert!(arena.chunk(0).is_none());
+    for i in 0..10_u8 {
+        arena.alloc_shared(i);
+    }
+
+    assert_eq!(arena.chunk(0), Some(&[0, 1, 2, 3][..]));
+    assert_eq!(arena.chunk(1), Some(&[4, 5, 6, 7][..]));
+    assert_eq!(arena.chunk(2), Some(&[8, 9][..]));
+    assert!(arena.chunk(3).is_none());
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn chunk_boundary() {
+    let mut arena = ManuallyDropArena::<_, 3, false, false>::new();
+    for i in 0..6_u8 {
+        arena.alloc_shared(i);
+    }
+    assert_eq!(arena.chunk(1), Some(&[3, 4, 5][..]));
+    assert_eq!(arena.chunk(2), None);
+    arena.reserve(1);
+    assert_eq!(arena.chunk(2), None);
+    assert!(arena.iter().copied().eq(0..6));
+    assert_eq!(arena.iter().nth(5), Some(&5));
+    assert_eq!(arena.iter().nth(6), None);
+    assert!(unsafe { arena.into_iter_unchecked() }.eq(0..6));
+}
+
+#[test]
+fn extend_from_slice() {
+    let mut arena = ManuallyDropArena::<_, 4>::new();
+    arena.extend_from_slice(&[]);
+    assert!(arena.is_empty());
+    arena.alloc(0_u8);
+    arena.extend_from_slice(&[1, 2, 3, 4, 5]);
+    arena.extend_from_slice(&[6, 7]);
+    arena.reserve(10);
+    arena.extend_from_slice(&[8, 9, 10, 11, 12]);
+    assert_eq!(arena.len(), 13);
+    assert!(unsafe { arena.iter_unchecked() }.copied().eq(0..13));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn visit_chunks() {
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    for i in 0..10_u8 {
+        arena.alloc_shared(i);
+    }
+    unsafe {
+        arena.visit_chunks_mut(<[u8]>::reverse);
+    }
+
+    let mut chunks = Vec::new();
+    arena.visit_chunks(|chunk| chunks.push(chunk.to_vec()));
+    assert_eq!(chunks, [&[3, 2, 1, 0][..], &[7, 6, 5, 4], &[9, 8]]);
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn drain_worklist() {
+    let mut arena = ManuallyDropArena::<_, 3, false, false>::new();
+    let mut visited = Vec::new();
+    arena.drain_worklist(|_, _| unreachable!());
+    arena.alloc_shared(1_u32);
+    arena.drain_worklist(|&n, arena| {
+        visited.push(n);
+        if n < 10 {
+            arena.alloc_shared(n * 2);
+            arena.alloc_shared(n * 3);
+        }
+    });
+    assert_eq!(
+        visited,
+        [1, 2, 3, 4, 6, 6, 9, 8, 12, 12, 18, 12, 18, 18, 27, 16, 24]
+    );
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn drain_worklist_handle() {
+    let mut arena = ManuallyDropArena::<u64, 4, false, false>::new();
+    arena.collect_from(0..5);
+    let mut visited = 0;
+    arena.drain_worklist(|&n, handle| {
+        visited += 1;
+        // The handle can't replace the arena (see the `compile_fail` example
+        // on `drain_worklist`), only add items to it.
+        if n == 4 {
+            for i in 0..15 {
+                handle.alloc_shared(100 + i);
+            }
+        }
+        assert!(handle.len() >= 5);
+    });
+    assert_eq!(visited, 20);
+    assert!(arena.iter().copied().eq((0..5).chain(100..115)));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn stats() {
+    let mut arena = ManuallyDropArena::<_, 4>::new();
+    for i in 0..6_u8 {
+        arena.alloc(i);
+    }
+    unsafe {
+        arena.drop();
+    }
+    arena.extend_from_slice(&[0; 10]);
+    arena.alloc(0);
+
+    let stats = arena.stats();
+    assert_eq!(stats.total_items, 17);
+    assert_eq!(stats.peak_len, 11);
+    assert_eq!(stats.peak_chunks, 3);
+    assert_eq!(stats.chunk_allocations, 5);
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn min_max() {
+    /// Compares only the key, so that equal items can be told apart.
+    #[derive(Debug)]
+    struct Item {
+        key: u8,
+        index: usize,
+    }
+
+    impl PartialEq for Item {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Item {}
+
+    impl PartialOrd for Item {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Item {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    let mut arena = ManuallyDropArena::<_, 3, false, false>::new();
+    assert!(arena.min().is_none());
+    assert!(arena.max().is_none());
+    for (index, key) in [5, 2, 9, 2, 1, 9, 1, 9].iter().copied().enumerate() {
+        arena.alloc_shared(Item {
+            key,
+            index,
+        });
+    }
+    assert_eq!(arena.min().map(|item| item.index), Some(4));
+    assert_eq!(arena.max().map(|item| item.index), Some(7));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn copy_to_slice() {
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    for i in 0..10_u8 {
+        arena.alloc_shared(i);
+    }
+
+    let mut buf = [0; 6];
+    assert_eq!(arena.copy_to_slice(&mut buf), 6);
+    assert_eq!(buf, [0, 1, 2, 3, 4, 5]);
+
+    let mut buf = [0; 12];
+    assert_eq!(arena.copy_to_slice(&mut buf), 10);
+    assert_eq!(buf, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0]);
+    assert_eq!(arena.copy_to_slice(&mut []), 0);
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn as_slice() {
+    let mut arena = ManuallyDropArena::<_, 3, false, false>::new();
+    assert_eq!(arena.as_slice(), Some(&[][..]));
+    for i in 0..3_u8 {
+        arena.alloc_shared(i);
+    }
+    assert!(arena.is_contiguous());
+    assert_eq!(arena.as_slice(), Some(&[0, 1, 2][..]));
+    arena.alloc_shared(3);
+    assert!(!arena.is_contiguous());
+    assert_eq!(arena.as_slice(), None);
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn positions_overhead() {
+    assert_eq!(ManuallyDropArena::<u8, 4, false>::positions_overhead(), 0);
+    assert!(ManuallyDropArena::<u8, 4, true>::positions_overhead() > 0);
+}
+
 #[test]
 fn ensure_dropped() {
     struct Item {
@@ -109,6 +343,57 @@ fn ensure_dropped() {
     assert!(drop_flags.iter().all(|f| f.get()));
 }
 
+#[test]
+fn drop_panic() {
+    struct Item<'a> {
+        value: u8,
+        drop_count: &'a Cell<usize>,
+    }
+
+    impl Drop for Item<'_> {
+        fn drop(&mut self) {
+            self.drop_count.set(self.drop_count.get() + 1);
+            if self.value == 5 {
+                silent_panic();
+            }
+        }
+    }
+
+    let drop_count = Cell::new(0);
+    let allocations = live_allocations();
+    let mut arena = ManuallyDropArena::<_, 4>::new();
+    for value in 0..12 {
+        arena.alloc(Item {
+            value,
+            drop_count: &drop_count,
+        });
+    }
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+        arena.drop();
+    }));
+    assert!(result.is_err());
+    drop(result);
+    assert_eq!(drop_count.get(), 12);
+    assert!(arena.is_empty());
+    assert!(!arena.has_undropped_items());
+    assert_eq!(live_allocations(), allocations);
+
+    // The arena should be reusable after a panic.
+    for value in 0..6 {
+        arena.alloc(Item {
+            value: value * 2,
+            drop_count: &drop_count,
+        });
+    }
+    assert_eq!(arena.len(), 6);
+    unsafe {
+        arena.drop();
+    }
+    assert_eq!(drop_count.get(), 18);
+    assert_eq!(live_allocations(), allocations);
+}
+
 #[test]
 #[cfg_attr(miri, ignore = "intentionally leaks memory")]
 fn ensure_leaked() {
@@ -124,6 +409,24 @@ fn ensure_leaked() {
     for i in 0..12 {
         arena.alloc(Item(i));
     }
+    assert!(arena.has_undropped_items());
+}
+
+#[test]
+fn has_undropped_items() {
+    let mut arena = ManuallyDropArena::<u8>::new();
+    assert!(!arena.has_undropped_items());
+    arena.alloc(1);
+    assert!(arena.has_undropped_items());
+    unsafe {
+        arena.drop();
+    }
+    assert!(!arena.has_undropped_items());
+    arena.reserve(1);
+    assert!(arena.has_undropped_items());
+    unsafe {
+        arena.drop();
+    }
 }
 
 #[test]
@@ -143,6 +446,35 @@ fn reuse() {
     }
 }
 
+#[test]
+fn into_arena() {
+    struct Item {
+        drop_flag: Rc<Cell<bool>>,
+    }
+
+    impl Drop for Item {
+        fn drop(&mut self) {
+            assert!(!self.drop_flag.get(), "value dropped twice");
+            self.drop_flag.set(true);
+        }
+    }
+
+    let drop_flags: [Rc<Cell<bool>>; 10] = Default::default();
+    let mut arena = ManuallyDropArena::<_, 4>::new();
+    for flag in drop_flags.iter().cloned() {
+        arena.alloc(Item {
+            drop_flag: flag,
+        });
+    }
+
+    let mut arena = unsafe { arena.into_arena() };
+    assert_eq!(arena.len(), 10);
+    assert_eq!(arena.iter_mut().count(), 10);
+    assert!(drop_flags.iter().all(|f| !f.get()));
+    drop(arena);
+    assert!(drop_flags.iter().all(|f| f.get()));
+}
+
 struct DropArena<T, Options: ArenaOptions<T>>(
     manually_drop::ManuallyDropArena<T, Options>,
 );
@@ -220,3 +552,457 @@ fn bad_position_reused_arena() {
     let drop = DropArena(arena);
     drop.0.iter_at(&pos);
 }
+
+#[test]
+fn from_vec() {
+    let allocations = live_allocations();
+    let mut arena =
+        ManuallyDropArena::<_, 4, false, false>::from_vec((0..8_u8).collect());
+    assert_eq!(arena.len(), 8);
+    assert_eq!(live_allocations(), allocations + 2);
+    assert!(arena.iter().copied().eq(0..8));
+    arena.alloc_shared(8);
+    assert!(arena.iter().copied().eq(0..9));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn from_array() {
+    let mut arena: ManuallyDropArena<_> = [1_u8, 2, 3].into();
+    assert!(unsafe { arena.iter_unchecked() }.copied().eq(1..=3));
+    unsafe {
+        arena.drop();
+    }
+    let mut arena: ManuallyDropArena<u8> = [].into();
+    assert!(arena.is_empty());
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn collect_from() {
+    let allocations = live_allocations();
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    assert_eq!(arena.collect_from(0..6_u8), 6);
+    assert_eq!(live_allocations(), allocations + 2);
+    assert_eq!(arena.collect_from((6..20).filter(|n| n % 2 == 0)), 7);
+    assert_eq!(arena.collect_from(None), 0);
+    assert!(arena.iter().copied().eq((0..6).chain((6..20).step_by(2))));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn extend() {
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    arena.extend(0..3_u8);
+    assert_eq!(arena.chunk_count(), 1);
+    arena.extend([3, 4]);
+    assert_eq!(arena.len(), 5);
+    assert_eq!(arena.chunk_count(), 2);
+    assert!(arena.iter().copied().eq(0..5));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn from_iter() {
+    let mut arena: ManuallyDropArena<_> = (0..100_u32).map(Rc::new).collect();
+    assert_eq!(arena.len(), 100);
+    assert!(unsafe { arena.iter_unchecked() }.map(|n| **n).eq(0..100));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn dealloc_empty() {
+    let allocations = live_allocations();
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    arena.collect_from((0..6).map(Rc::new));
+    assert_eq!(live_allocations(), allocations + 2 + 6);
+    let items: Vec<_> = (0..arena.len())
+        .map(|i| unsafe { arena.slot_ptr(i).unwrap().as_ptr().read() })
+        .collect();
+    unsafe {
+        arena.set_len(0);
+        arena.dealloc_empty();
+    }
+    assert!(!arena.has_undropped_items());
+    assert_eq!(live_allocations(), allocations + 6 + 1);
+    assert!(items.iter().map(|n| **n).eq(0..6));
+}
+
+#[cfg(all(feature = "debug-poison", debug_assertions))]
+#[test]
+fn debug_poison() {
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    arena.collect_from((0..6_u32).map(Rc::new));
+    let ptr = arena.slot_ptr(5).unwrap().as_ptr().cast::<u8>();
+    unsafe {
+        arena.clear();
+    }
+    let bytes = unsafe {
+        core::slice::from_raw_parts(ptr, core::mem::size_of::<Rc<u32>>())
+    };
+    assert!(bytes.iter().all(|&b| b == crate::chunk::POISON));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn iter_ptr_at_raw() {
+    let mut arena = ManuallyDropArena::<_, 4, true, true>::new();
+    arena.collect_from(0..10_u8);
+    let position = arena.position_at_index(3).unwrap();
+    let ptrs = unsafe { arena.iter_ptr_at_raw(&position) };
+    for ptr in ptrs.clone() {
+        unsafe {
+            *ptr.as_ptr() *= 2;
+        }
+    }
+    assert!(ptrs.map(|p| unsafe { *p.as_ptr() }).eq((3..10).map(|n| n * 2)));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn retain_chunks() {
+    let allocations = live_allocations();
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    arena.collect_from(0..20_u8);
+    assert_eq!(live_allocations(), allocations + 5);
+    unsafe {
+        arena.set_len(0);
+        arena.retain_chunks(2);
+    }
+    assert_eq!(live_allocations(), allocations + 2);
+    arena.collect_from(0..8);
+    assert_eq!(live_allocations(), allocations + 2);
+    arena.alloc_shared(8);
+    assert_eq!(live_allocations(), allocations + 3);
+    assert!(arena.iter().copied().eq(0..9));
+    unsafe {
+        arena.set_len(0);
+        arena.retain_chunks(0);
+    }
+    assert!(!arena.has_undropped_items());
+}
+
+#[test]
+fn alloc_with() {
+    let mut arena = ManuallyDropArena::<[u8; 4096], 2>::new();
+    let item = arena.alloc_with(|| [1; 4096]);
+    assert_eq!(item.as_ptr(), arena.slot_ptr(0).unwrap().as_ptr().cast());
+    let item = arena.alloc_shared_with(|| [2; 4096]);
+    assert_eq!(item.as_ptr(), arena.slot_ptr(1).unwrap().as_ptr().cast());
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        arena.alloc_with(|| silent_panic());
+    }));
+    assert!(result.is_err());
+    assert_eq!(arena.len(), 2);
+    assert_eq!(unsafe { arena.iter_unchecked() }.map(|a| a[0]).sum::<u8>(), 3);
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn chunks_needed() {
+    const NEEDED: usize = ManuallyDropArena::<u8, 4>::chunks_needed(9);
+    assert_eq!(NEEDED, 3);
+    assert_eq!(ManuallyDropArena::<u8, 4>::chunks_needed(0), 0);
+    assert_eq!(ManuallyDropArena::<u8, 4>::chunks_needed(8), 2);
+    assert_eq!(ManuallyDropArena::<u8, 0>::chunks_needed(0), 0);
+    assert_eq!(ManuallyDropArena::<u8, 0>::chunks_needed(1), usize::MAX);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn tail_utilization() {
+    let mut arena = ManuallyDropArena::<u8, 4>::new();
+    assert_eq!(arena.tail_utilization(), 0.0);
+    arena.alloc(0);
+    assert_eq!(arena.tail_utilization(), 0.25);
+    for i in 1..4 {
+        arena.alloc(i);
+    }
+    assert_eq!(arena.tail_utilization(), 1.0);
+    arena.alloc(4);
+    arena.alloc(5);
+    assert_eq!(arena.tail_utilization(), 0.5);
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn reserve() {
+    let allocations = live_allocations();
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    arena.reserve(10);
+    assert_eq!(live_allocations(), allocations + 3);
+    assert!(arena.iter().next().is_none());
+
+    for i in 0..10_u8 {
+        arena.alloc_shared(i);
+    }
+    assert_eq!(live_allocations(), allocations + 3);
+    assert!(arena.iter().copied().eq(0..10));
+
+    arena.reserve(6);
+    assert_eq!(live_allocations(), allocations + 4);
+    assert!(arena.try_reserve(6).is_some());
+    assert_eq!(live_allocations(), allocations + 4);
+    assert!(arena.iter().copied().eq(0..10));
+    unsafe {
+        arena.drop();
+    }
+    assert_eq!(live_allocations(), allocations);
+}
+
+#[test]
+fn reserve_into_iter() {
+    let allocations = live_allocations();
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    arena.alloc_shared(Rc::new(1_u8));
+    arena.alloc_shared(Rc::new(2_u8));
+    arena.reserve(8);
+    let items: Vec<_> = unsafe { arena.into_iter_unchecked() }.collect();
+    assert_eq!(items.len(), 2);
+    drop(items);
+    assert_eq!(live_allocations(), allocations);
+}
+
+#[test]
+fn reserve_max_chunks() {
+    let mut arena = ManuallyDropArena::<u8, 4, false, false, 2>::new();
+    assert!(arena.try_reserve(9).is_none());
+    assert!(arena.try_reserve(8).is_some());
+    for i in 0..8 {
+        arena.alloc_shared(i);
+    }
+    assert!(arena.try_alloc_shared(8).is_none());
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn set_len() {
+    let mut arena = ManuallyDropArena::<_, 4, false, false>::new();
+    arena.alloc_shared(0_u8);
+    arena.reserve(5);
+    assert!(arena.slot_ptr(8).is_none());
+    for i in 1..8 {
+        let slot = arena.slot_ptr(usize::from(i)).unwrap();
+        unsafe {
+            slot.as_ptr().write(i);
+        }
+    }
+    unsafe {
+        arena.set_len(8);
+    }
+    assert!(arena.iter().copied().eq(0..8));
+    assert!(arena.try_alloc_shared(8).is_some());
+
+    unsafe {
+        arena.set_len(4);
+    }
+    assert!(arena.iter().copied().eq(0..4));
+    arena.alloc_shared(10);
+    assert!(arena.iter().copied().eq([0, 1, 2, 3, 10]));
+    unsafe {
+        arena.set_len(0);
+    }
+    assert!(arena.iter().next().is_none());
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn set_len_reuses_chunks() {
+    let mut arena = ManuallyDropArena::<_, 4>::new();
+    for i in 0..5_u8 {
+        arena.alloc(i);
+    }
+    let allocations = live_allocations();
+    for _ in 0..3 {
+        unsafe {
+            arena.set_len(4);
+        }
+        arena.alloc(4);
+    }
+    assert_eq!(live_allocations(), allocations);
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn split_off_chunks() {
+    let allocations = live_allocations();
+    let mut arena = ManuallyDropArena::<_, 3, false, false>::new();
+    for i in 0..8_u8 {
+        arena.alloc_shared(i);
+    }
+
+    let mut other = unsafe { arena.split_off_chunks(1) };
+    assert!(arena.iter().copied().eq(0..3));
+    assert!(other.iter().copied().eq(3..8));
+    arena.alloc_shared(10);
+    other.alloc_shared(11);
+    assert!(arena.iter().copied().eq([0, 1, 2, 10]));
+    assert!(other.iter().copied().eq([3, 4, 5, 6, 7, 11]));
+
+    // Split off only spare chunks.
+    arena.reserve(5);
+    let mut spare = unsafe { arena.split_off_chunks(2) };
+    assert!(spare.is_empty());
+    spare.alloc_shared(12);
+    arena.alloc_shared(13);
+    assert!(spare.iter().copied().eq([12]));
+    assert!(arena.iter().copied().eq([0, 1, 2, 10, 13]));
+
+    let mut all = unsafe { other.split_off_chunks(0) };
+    assert!(other.is_empty());
+    assert!(all.iter().copied().eq([3, 4, 5, 6, 7, 11]));
+    assert!(unsafe { all.split_off_chunks(2) }.is_empty());
+
+    unsafe {
+        arena.drop();
+        other.drop();
+        spare.drop();
+        all.drop();
+    }
+    assert_eq!(live_allocations(), allocations);
+}
+
+#[test]
+fn split_off_chunks_full_tail() {
+    let mut arena = ManuallyDropArena::<_, 2, false, false>::new();
+    for i in 0..4_u8 {
+        arena.alloc_shared(i);
+    }
+    arena.reserve(1);
+    let mut other = unsafe { arena.split_off_chunks(2) };
+    assert!(arena.iter().copied().eq(0..4));
+    assert!(other.is_empty());
+    arena.alloc_shared(4);
+    other.alloc_shared(5);
+    assert!(arena.iter().copied().eq(0..5));
+    assert!(other.iter().copied().eq([5]));
+    unsafe {
+        arena.drop();
+        other.drop();
+    }
+}
+
+#[test]
+#[should_panic(expected = "chunk index out of bounds")]
+fn split_off_chunks_out_of_bounds() {
+    let mut arena = ManuallyDropArena::<u8, 2>::new();
+    let _ = unsafe { arena.split_off_chunks(1) };
+}
+
+#[test]
+fn merge_sorted() {
+    let mut a = ManuallyDropArena::<_, 3, false, false>::new();
+    let mut b = ManuallyDropArena::<_, 3, false, false>::new();
+    for item in [(1, 'a'), (3, 'a'), (3, 'b'), (8, 'a')] {
+        a.alloc_shared(item);
+    }
+    for item in [(0, 'c'), (3, 'c'), (4, 'c')] {
+        b.alloc_shared(item);
+    }
+
+    let mut merged =
+        ManuallyDropArena::merge_sorted(&a, &b, |x, y| x.0.cmp(&y.0));
+    assert!(merged.iter().copied().eq([
+        (0, 'c'),
+        (1, 'a'),
+        (3, 'a'),
+        (3, 'b'),
+        (3, 'c'),
+        (4, 'c'),
+        (8, 'a'),
+    ]));
+    unsafe {
+        a.drop();
+        b.drop();
+        merged.drop();
+    }
+}
+
+#[test]
+fn chunk_ranges() {
+    let mut arena = ManuallyDropArena::<u32, 4>::new();
+    assert_eq!(arena.chunk_ranges().count(), 0);
+    let items: Vec<_> = (0..5).map(|i| arena.alloc(i) as *const u32).collect();
+
+    let ranges: Vec<_> = arena.chunk_ranges().collect();
+    assert_eq!(ranges.len(), 2);
+    for range in &ranges {
+        assert_eq!(range.end as usize - range.start as usize, 16);
+    }
+    for (i, item) in items.into_iter().enumerate() {
+        assert!(ranges[i / 4].contains(&item.cast()));
+    }
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn chunk_ranges_zst() {
+    let mut arena = ManuallyDropArena::<(), 4>::new();
+    for _ in 0..5 {
+        arena.alloc(());
+    }
+    let ranges: Vec<_> = arena.chunk_ranges().collect();
+    assert_eq!(ranges.len(), 1);
+    assert!(ranges.iter().all(|range| range.start == range.end));
+    unsafe {
+        arena.drop();
+    }
+}
+
+#[test]
+fn unused_slots() {
+    let allocations = live_allocations();
+    let arena = crate::Arena::<_, 4>::new();
+    arena.alloc(0_u8);
+    arena.alloc_array([1, 2, 3, 4]);
+    arena.alloc(5);
+    let mut arena = arena.into_manually_drop();
+    assert_eq!(arena.capacity(), 9);
+
+    // The first chunk keeps its unused slots, and the second becomes the tail.
+    let mut other = unsafe { arena.split_off_chunks(2) };
+    assert_eq!(arena.capacity(), 5);
+    assert!(unsafe { arena.iter_unchecked() }.copied().eq(0..5));
+    assert!(unsafe { other.iter_unchecked() }.copied().eq([5]));
+
+    // Shrinking the arena into the first chunk makes its slots usable again.
+    unsafe {
+        arena.set_len(1);
+    }
+    assert_eq!(arena.capacity(), 8);
+    arena.extend_from_slice(&[6, 7, 8, 9]);
+    assert_eq!(arena.chunk_count(), 2);
+    assert!(unsafe { arena.iter_unchecked() }.copied().eq([0, 6, 7, 8, 9]));
+
+    unsafe {
+        arena.drop();
+        other.drop();
+    }
+    assert_eq!(live_allocations(), allocations);
+}
@@ -17,12 +17,61 @@
  * along with fixed-typed-arena. If not, see <https://www.gnu.org/licenses/>.
  */
 
+#![allow(clippy::should_panic_without_expect)]
+
+use crate::manually_drop::AllocFailureKind;
+use crate::static_arena::StaticArena;
+use crate::uninit::UninitArena;
 use crate::Arena;
+use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::cell::Cell;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomPinned;
+use core::mem::MaybeUninit;
+use core::pin::Pin;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::hash_map::DefaultHasher;
+use std::panic::{self, AssertUnwindSafe};
 
 mod manually_drop;
 
+std::thread_local! {
+    static LIVE_ALLOCATIONS: Cell<isize> = const { Cell::new(0) };
+}
+
+/// Counts the live allocations made by each thread, so that tests can check
+/// for leaks.
+struct CountingAlloc;
+
+// SAFETY: This type simply forwards to `System`.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = LIVE_ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        // SAFETY: Checked by caller.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE_ALLOCATIONS.try_with(|n| n.set(n.get() - 1));
+        // SAFETY: Checked by caller.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+/// Returns the number of live allocations made by the current thread.
+fn live_allocations() -> isize {
+    LIVE_ALLOCATIONS.with(Cell::get)
+}
+
+/// Panics without invoking the panic hook, which may allocate memory.
+fn silent_panic() -> ! {
+    panic::resume_unwind(Box::new(()))
+}
+
 #[test]
 fn empty() {
     let arena = Arena::<u8>::new();
@@ -54,6 +103,321 @@ fn multiple_chunks() {
     assert_eq!(arena.len(), 20);
 }
 
+#[test]
+fn chunk_boundary() {
+    let mut arena = Arena::<_, 4, true, false>::new();
+    for i in 0..4_u8 {
+        arena.alloc_shared(i);
+    }
+    assert_eq!(arena.len(), 4);
+    assert!(arena.iter().copied().eq(0..4));
+    assert_eq!(arena.get_mut(3).copied(), Some(3));
+    assert_eq!(arena.get_mut(4), None);
+
+    let mut iter = arena.iter();
+    iter.nth(3);
+    assert!(iter.is_at_end());
+    let end = iter.as_position();
+    let index_end = arena.position_at_index(4).unwrap();
+    assert!(arena.iter_at(&end).next().is_none());
+
+    arena.alloc_shared(4);
+    assert_eq!(arena.len(), 5);
+    assert!(arena.iter().copied().eq(0..5));
+    assert_eq!(arena.get_mut(4).copied(), Some(4));
+    assert_eq!(arena.get_mut(5), None);
+    assert!(arena.iter_at(&end).copied().eq([4]));
+    assert!(arena.iter_at(&index_end).copied().eq([4]));
+
+    for i in 5..8 {
+        arena.alloc_shared(i);
+    }
+    assert!(arena.iter().copied().eq(0..8));
+    assert!(arena.iter_at(&end).copied().eq(4..8));
+    assert!(arena.into_iter().eq(0..8));
+}
+
+#[test]
+fn chunk_size_for() {
+    use crate::chunk_size_for;
+    assert_eq!(chunk_size_for::<u32>(64), 16);
+    assert_eq!(chunk_size_for::<u32>(66), 16);
+    assert_eq!(chunk_size_for::<u32>(2), 1);
+    assert_eq!(chunk_size_for::<()>(64), 1);
+
+    let arena = Arena::<u32, { chunk_size_for::<u32>(16) }>::new();
+    let items: Vec<_> = (0..10).map(|i| &*arena.alloc(i)).collect();
+    assert!(items.into_iter().copied().eq(0..10));
+}
+
+#[test]
+fn alloc_scoped() {
+    let mut arena = Arena::<_, 4>::new();
+    for i in 0..6_u8 {
+        let doubled = arena.alloc_scoped(i, |n| {
+            *n += 1;
+            *n * 2
+        });
+        assert_eq!(doubled, (i + 1) * 2);
+    }
+    assert!(arena.iter_mut().map(|n| *n).eq(1..7));
+}
+
+#[test]
+fn from_vec() {
+    let arena = Arena::<_, 4>::from_vec((0..10_u8).map(Box::new).collect());
+    assert_eq!(arena.len(), 10);
+    assert!(arena.into_iter().map(|n| *n).eq(0..10));
+    assert!(Arena::<u8, 4>::from_vec(Vec::new()).is_empty());
+}
+
+#[test]
+fn from_array() {
+    let arena: Arena<_> = [1, 2, 3].map(Box::new).into();
+    assert!(arena.into_iter().map(|n| *n).eq(1..=3));
+}
+
+#[test]
+fn alloc_with() {
+    let mut arena = Arena::<_, 2>::new();
+    let first: *mut _ = arena.alloc_with(|| [1_u8; 4096]);
+    let second: *mut _ = arena.alloc_with(|| [2; 4096]);
+    let chunk = arena.chunks_mut().next().unwrap().as_mut_ptr();
+    assert_eq!(first, chunk);
+    assert_eq!(second, chunk.wrapping_add(1));
+
+    // Items allocated by `f` are placed after the item.
+    let arena = Arena::<_>::new();
+    let a = arena.alloc_with(|| *arena.alloc(1_u8) + 1);
+    assert_eq!(*a, 2);
+    assert!(arena.into_iter().eq([2, 1]));
+}
+
+#[test]
+fn alloc_with_panic() {
+    let mut arena = Arena::<_, 4>::new();
+    arena.alloc(Box::new(0));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        arena.alloc_with(|| silent_panic());
+    }));
+    assert!(result.is_err());
+    assert_eq!(arena.len(), 1);
+    arena.alloc(Box::new(1));
+    assert!(arena.iter_mut().map(|b| **b).eq([0, 1]));
+
+    // If `f` allocates items and then panics, the arena's items are leaked.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        arena.alloc_with(|| {
+            arena.alloc(Box::new(2));
+            silent_panic();
+        });
+    }));
+    assert!(result.is_err());
+    assert!(arena.is_empty());
+    arena.alloc(Box::new(3));
+    assert!(arena.into_iter().map(|b| *b).eq([3]));
+}
+
+#[test]
+fn alloc_extend() {
+    let arena = Arena::<_, 4>::new();
+    assert_eq!(arena.alloc_extend(0..6), 6);
+    assert_eq!(arena.alloc_extend(None), 0);
+    assert!(arena.into_iter().eq(0..6));
+}
+
+#[test]
+fn alloc_slice_from_iter() {
+    let arena = Arena::<_, 4>::new();
+    assert_eq!(arena.alloc_slice_from_iter(0..3), Some(&mut [0, 1, 2][..]));
+    assert_eq!(arena.alloc_slice_from_iter(3..5), None);
+    assert_eq!(arena.alloc_slice_from_iter(3..4), Some(&mut [3][..]));
+    // The tail chunk is full, so the next chunk is used.
+    assert_eq!(arena.alloc_slice_from_iter(4..8), Some(&mut [4, 5, 6, 7][..]));
+    assert_eq!(arena.alloc_slice_from_iter(0..0), Some(&mut [][..]));
+    assert_eq!(arena.alloc_slice_from_iter(0..5), None);
+    assert_eq!(arena.len(), 8);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        arena.alloc_slice_from_iter((0..2).map(|n| *arena.alloc(n)))
+    }));
+    assert!(result.is_err());
+    assert!(arena.into_iter().eq((0..8).chain([0, 0])));
+}
+
+#[test]
+fn alloc_array() {
+    let arena = Arena::<_, 4>::new();
+    assert_eq!(arena.alloc_array([0, 1]), &[0, 1]);
+    assert_eq!(arena.alloc_array([2, 3]), &[2, 3]);
+    // The tail chunk is full, so the next chunk is used.
+    let array = arena.alloc_array([4, 5, 6, 7]);
+    array[0] = 8;
+    assert!(arena.alloc_array([]).is_empty());
+    assert_eq!(arena.chunk_count(), 2);
+    arena.alloc(9);
+    assert_eq!(arena.try_alloc_array([10, 11, 12]), Some(&mut [10, 11, 12]));
+    assert!(arena.into_iter().eq([0, 1, 2, 3, 8, 5, 6, 7, 9, 10, 11, 12]));
+
+    let arena = Arena::<_, 4, false, true, 2>::new();
+    arena.alloc_array([0, 1, 2]);
+    assert_eq!(arena.try_alloc_array([3, 4]), Some(&mut [3, 4]));
+    assert!(arena.try_alloc_array([5, 6, 7]).is_none());
+    assert_eq!(arena.len(), 5);
+}
+
+#[test]
+#[should_panic(expected = "cannot be greater than the chunk size")]
+fn alloc_array_too_large() {
+    let arena = Arena::<_, 4>::new();
+    arena.alloc_array([0; 5]);
+}
+
+#[test]
+fn alloc_array_split() {
+    let mut arena = Arena::<_, 4, true>::new();
+    let first: *mut _ = arena.alloc(Box::new(0));
+    let array: *mut _ = arena.alloc_array([1, 2, 3, 4].map(Box::new));
+    // The array doesn't fit in the rest of the first chunk, so it starts a
+    // new one, and the rest of the first chunk is left unused.
+    assert_eq!(arena.chunk_count(), 2);
+    assert_eq!(arena.capacity(), 5);
+    let chunks = arena.chunks_mut().map(<[_]>::as_mut_ptr);
+    assert!(chunks.eq([first, array.cast()]));
+    arena.alloc(Box::new(5));
+    assert_eq!(arena.chunk_count(), 3);
+
+    assert!(arena.iter_mut().map(|b| **b).eq(0..6));
+    assert!(arena.iter_mut().rev().map(|b| **b).eq((0..6).rev()));
+    assert_eq!(arena.iter_mut().nth(4).map(|b| **b), Some(4));
+    assert_eq!(arena.get_mut(1).map(|b| **b), Some(1));
+    let position = arena.position_at_index(1).unwrap();
+    assert!(arena.iter_mut_at(&position).map(|b| **b).eq(1..6));
+    let mut cursor = arena.cursor_mut();
+    (0..5).for_each(|_| cursor.move_next());
+    assert_eq!(cursor.index(), Some(5));
+
+    // Clearing the arena makes the unused slots usable again.
+    arena.clear();
+    assert_eq!(arena.capacity(), 12);
+    arena.alloc_extend((0..12).map(Box::new));
+    assert_eq!(arena.chunk_count(), 3);
+    assert!(arena.into_iter().map(|b| *b).eq(0..12));
+}
+
+#[test]
+fn extend() {
+    let arena = Arena::<_, 4>::new();
+    arena.alloc(0);
+    (&arena).extend(1..6);
+    assert_eq!(arena.len(), 6);
+    assert_eq!(arena.chunk_count(), 2);
+    let mut arena_ref = &arena;
+    arena_ref.extend(6..10);
+    assert_eq!(arena.len(), 10);
+    assert_eq!(arena.chunk_count(), 3);
+    assert!(arena.into_iter().eq(0..10));
+}
+
+#[test]
+fn from_iter() {
+    let arena: Arena<_> = (0..100_u32).collect();
+    assert_eq!(arena.len(), 100);
+    assert!(arena.into_iter().eq(0..100));
+}
+
+#[test]
+fn clear() {
+    let allocations = live_allocations();
+    let mut arena = Arena::<_, 4, true>::new();
+    arena.clear();
+    arena.extend_refs((0..10_u8).map(Box::new));
+    assert_eq!(live_allocations(), allocations + 14);
+    let position = arena.iter_mut().as_position();
+    arena.clear();
+    assert!(arena.is_empty());
+    // The chunks are kept; `position` keeps the old position counter alive.
+    assert_eq!(live_allocations(), allocations + 5);
+
+    arena.extend_refs((0..12_u8).map(Box::new));
+    assert_eq!(live_allocations(), allocations + 17);
+    assert!(arena.iter_mut().map(|n| **n).eq(0..12));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        arena.iter_mut_at(&position);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn capacity() {
+    let arena = Arena::<_, 4>::new();
+    assert_eq!((arena.chunk_count(), arena.capacity()), (0, 0));
+    arena.alloc(0_u8);
+    assert_eq!((arena.chunk_count(), arena.capacity()), (1, 4));
+    arena.reserve(4);
+    assert_eq!((arena.chunk_count(), arena.capacity()), (2, 8));
+    arena.extend_refs(1..9);
+    assert_eq!((arena.chunk_count(), arena.capacity()), (3, 12));
+}
+
+#[test]
+fn bytes_allocated() {
+    type Chunk = crate::chunk::ChunkRef<u32, [MaybeUninit<u32>; 8]>;
+    let arena = Arena::<u32, 8>::new();
+    assert_eq!(arena.bytes_allocated(), 0);
+    arena.extend_refs(0..20);
+    assert_eq!(arena.bytes_allocated(), Chunk::LAYOUT.size() * 3);
+    assert!(Chunk::LAYOUT.size() >= 8 * 4 + core::mem::size_of::<usize>());
+}
+
+#[test]
+fn alloc_if() {
+    let arena = Arena::<_>::new();
+    assert_eq!(arena.alloc_if(true, || 1_u8), Some(&mut 1));
+    assert_eq!(arena.alloc_if(false, || unreachable!()), None);
+    assert_eq!(arena.len(), 1);
+
+    // Like `alloc_with`, the item's slot is claimed before `f` is called.
+    let mut arena = Arena::<_>::new();
+    arena.alloc_if(true, || *arena.alloc(1_u8) + 1);
+    assert!(arena.iter_mut().map(|n| *n).eq([2, 1]));
+}
+
+#[test]
+fn extend_refs() {
+    let arena = Arena::<_, 3>::new();
+    let refs = arena.extend_refs(0..7_u8);
+    assert_eq!(refs.len(), 7);
+    for item in refs {
+        *item *= 2;
+    }
+    assert!(arena.into_iter().eq((0..7).map(|n| n * 2)));
+}
+
+#[test]
+fn alloc_flatten() {
+    let arena = Arena::<_, 5, false, false>::new();
+    let tiles = [[0_u8, 1, 2], [3, 4, 5], [6, 7, 8]];
+    assert_eq!(arena.alloc_flatten(tiles), 9);
+    assert_eq!(arena.alloc_flatten(Vec::<[u8; 2]>::new()), 0);
+    assert!(arena.iter().copied().eq(0..9));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn dedup_into() {
+    let arena = Arena::<_, 2, false, false>::new();
+    for item in [3_u8, 1, 3, 2, 1, 3] {
+        arena.alloc_shared(item);
+    }
+    let (deduped, indices) = arena.dedup_into();
+    assert!(deduped.iter().copied().eq([3, 1, 2]));
+    assert_eq!(indices.len(), 3);
+    assert_eq!(indices[&3], 0);
+    assert_eq!(indices[&1], 1);
+    assert_eq!(indices[&2], 2);
+}
+
 #[test]
 fn ensure_dropped() {
     struct Item<'a> {
@@ -81,6 +445,53 @@ fn ensure_dropped() {
     assert!(drop_flags.iter().all(Cell::get));
 }
 
+#[test]
+fn drop_panic() {
+    struct Item<'a> {
+        value: u8,
+        drop_count: &'a Cell<usize>,
+    }
+
+    impl Drop for Item<'_> {
+        fn drop(&mut self) {
+            self.drop_count.set(self.drop_count.get() + 1);
+            if self.value == 3 {
+                silent_panic();
+            }
+        }
+    }
+
+    let drop_count = Cell::new(0);
+    let allocations = live_allocations();
+    let arena = Arena::<_, 4>::new();
+    for value in 0..10 {
+        arena.alloc(Item {
+            value,
+            drop_count: &drop_count,
+        });
+    }
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| drop(arena)));
+    assert!(result.is_err());
+    drop(result);
+    assert_eq!(drop_count.get(), 10);
+    assert_eq!(live_allocations(), allocations);
+}
+
+#[test]
+fn into_iter_partial_drop() {
+    let allocations = live_allocations();
+    let arena = Arena::<_, 4>::new();
+    arena.reserve(12);
+    for i in 0..10_u8 {
+        arena.alloc(i);
+    }
+    let mut iter = arena.into_iter();
+    assert_eq!(iter.nth(4), Some(4));
+    drop(iter);
+    assert_eq!(live_allocations(), allocations);
+}
+
 #[cfg(feature = "dropck_eyepatch")]
 #[test]
 fn same_life_ref() {
@@ -105,6 +516,230 @@ fn zero_chunk_size() {
     arena.alloc(0_u8);
 }
 
+#[test]
+fn zero_sized() {
+    std::thread_local! {
+        static DROPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct Item;
+
+    impl Drop for Item {
+        fn drop(&mut self) {
+            DROPS.with(|d| d.set(d.get() + 1));
+        }
+    }
+
+    let allocations = live_allocations();
+    let mut arena = Arena::<_, 4, true>::new();
+    for _ in 0..5000 {
+        arena.alloc(Item);
+    }
+    assert_eq!(live_allocations(), allocations + 2);
+    assert_eq!(arena.len(), 5000);
+    assert_eq!(arena.iter_mut().count(), 5000);
+    assert!(arena.iter_mut().nth(4999).is_some());
+    assert!(arena.iter_mut().nth(5000).is_none());
+
+    let mut iter = arena.iter_mut();
+    iter.nth(1234);
+    let position = iter.as_position();
+    drop(iter);
+    assert_eq!(arena.iter_mut_at(&position).count(), 5000 - 1235);
+    let mut cursor = arena.cursor_mut();
+    cursor.seek(&position);
+    assert_eq!(cursor.index(), Some(1235));
+
+    drop(cursor);
+    drop(position);
+    drop(arena);
+    assert_eq!(DROPS.with(Cell::get), 5000);
+    assert_eq!(live_allocations(), allocations);
+
+    let arena = Arena::<(), 4, false, false>::new();
+    arena.reserve(3000);
+    for _ in 0..3000 {
+        arena.alloc_shared(());
+    }
+    assert_eq!(arena.iter().count(), 3000);
+    assert_eq!(arena.get(2999), Some(&()));
+    assert_eq!(arena.get(3000), None);
+    assert_eq!(arena.into_iter().count(), 3000);
+}
+
+#[test]
+fn max_chunks() {
+    let arena = Arena::<_, 4, false, true, 2>::new();
+    for i in 0..8_u8 {
+        assert_eq!(arena.try_alloc(i).copied(), Some(i));
+    }
+    assert!(arena.try_alloc(8).is_none());
+    assert!(arena.try_alloc_shared(8).is_none());
+    assert_eq!(arena.len(), 8);
+}
+
+#[test]
+fn try_alloc_detailed() {
+    let arena = Arena::<_, 4, false, true, 2>::new();
+    for i in 0..8_u8 {
+        assert_eq!(arena.try_alloc_detailed(i).map(|n| *n), Ok(i));
+    }
+    assert_eq!(
+        arena.try_alloc_detailed(8).map(|n| *n),
+        Err((8, AllocFailureKind::CapacityCapReached)),
+    );
+    assert_eq!(
+        arena.try_alloc_shared_detailed(9),
+        Err((9, AllocFailureKind::CapacityCapReached)),
+    );
+    assert_eq!(arena.len(), 8);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn alloc_failure_error() {
+    use std::error::Error;
+    use std::string::ToString;
+
+    let arena = Arena::<_, 4, false, false, 1>::new();
+    arena.alloc_extend(0..4_u8);
+    let (_, kind) = arena.try_alloc_shared_detailed(4).unwrap_err();
+    let error: &dyn Error = &kind;
+    assert_eq!(error.to_string(), "arena cannot hold any more items");
+    assert_eq!(AllocFailureKind::Oom.to_string(), "memory allocation failed");
+}
+
+#[test]
+fn rechunk() {
+    let arena = Arena::<_, 3, false, false>::new();
+    for i in 0..10_u8 {
+        arena.alloc_shared(i);
+    }
+    let arena = arena.rechunk::<4>();
+    assert!(arena.iter().copied().eq(0..10));
+    assert!(arena.iter().remaining_chunks().map(<[_]>::len).eq([4, 4, 2]));
+}
+
+#[test]
+fn reserve() {
+    let arena = Arena::<_, 4, false, true, 3>::new();
+    let item = arena.alloc(0_u8);
+    arena.reserve(7);
+    *item = 10;
+    for i in 1..8 {
+        arena.alloc(i);
+    }
+    assert!(arena.try_reserve(5).is_none());
+    assert!(arena.try_reserve(4).is_some());
+    assert_eq!(*item, 10);
+    assert_eq!(arena.len(), 8);
+}
+
+#[test]
+fn extend_until_full() {
+    let arena = Arena::<_, 4, false, true, 2>::new();
+    let mut rest = arena.extend_until_full(0..3_u8);
+    assert_eq!(rest.next(), None);
+    let rest = arena.extend_until_full(3..12);
+    assert!(rest.eq(8..12));
+    assert_eq!(arena.len(), 8);
+    assert!(arena.extend_until_full(0..1).eq(0..1));
+}
+
+#[test]
+#[should_panic(expected = "more than `MaxChunks` chunks")]
+fn max_chunks_exceeded() {
+    let arena = Arena::<_, 4, false, true, 2>::new();
+    for i in 0..9_u8 {
+        arena.alloc(i);
+    }
+}
+
+#[test]
+fn pinned() {
+    struct Item {
+        value: u8,
+        _pinned: PhantomPinned,
+    }
+
+    let arena = crate::Arena::<
+        _,
+        2,     /* CHUNK_SIZE */
+        false, /* SUPPORTS_POSITIONS */
+        false, /* MUTABLE */
+        { usize::MAX },
+        false, /* TRACK_BORROWS */
+        true,  /* PINNED */
+    >::new();
+    let mut items = Vec::new();
+    for value in 0..5 {
+        let item = arena.alloc_shared(Item {
+            value,
+            _pinned: PhantomPinned,
+        });
+        // SAFETY: Items in a pinned arena are never moved.
+        items.push(unsafe { Pin::new_unchecked(item) });
+    }
+    assert!(items.iter().map(|item| item.value).eq(0..5));
+    assert!(arena.iter().map(|item| item.value).eq(0..5));
+}
+
+#[test]
+fn alloc_pin() {
+    struct Item {
+        value: u8,
+        _pinned: PhantomPinned,
+    }
+
+    impl Item {
+        fn increment(self: Pin<&mut Self>) {
+            // SAFETY: `value` is not structurally pinned.
+            unsafe { self.get_unchecked_mut() }.value += 1;
+        }
+    }
+
+    let arena =
+        crate::Arena::<_, 2, false, true, { usize::MAX }, false, true>::new();
+    let mut items: Vec<_> = (0..5)
+        .map(|value| {
+            arena.alloc_pin(Item {
+                value,
+                _pinned: PhantomPinned,
+            })
+        })
+        .collect();
+    for item in &mut items {
+        item.as_mut().increment();
+    }
+    assert!(items.iter().map(|item| item.value).eq(1..6));
+}
+
+#[test]
+fn alloc_tracked() {
+    let arena =
+        crate::Arena::<_, 2, false, false, { usize::MAX }, true>::new();
+    arena.alloc_shared(0_u8);
+    let mut item1 = arena.alloc_tracked(1);
+    let mut item2 = arena.alloc_tracked(2);
+    *item1 += 10;
+    *item2 += 10;
+    drop(item1);
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| arena.iter())).is_err());
+    drop(item2);
+    assert!(arena.iter().copied().eq([0, 11, 12]));
+    assert_eq!(arena[2], 12);
+}
+
+#[test]
+#[should_panic(expected = "mutably borrowed")]
+fn alloc_tracked_index() {
+    let arena =
+        crate::Arena::<_, 2, false, false, { usize::MAX }, true>::new();
+    arena.alloc_shared(0_u8);
+    let _item = arena.alloc_tracked(1);
+    let _ = arena[0];
+}
+
 #[test]
 fn iter() {
     type Arena<T> = self::Arena<
@@ -130,6 +765,159 @@ fn iter_unchecked() {
     assert!(unsafe { arena.iter_unchecked() }.copied().eq(0..32));
 }
 
+#[test]
+fn remaining_chunks() {
+    let arena = Arena::<_, 3, false, false>::new();
+    let mut iter = arena.iter();
+    assert_eq!(iter.remaining_chunks().count(), 0);
+    for i in 0..8_u8 {
+        arena.alloc_shared(i);
+    }
+
+    iter = arena.iter();
+    let chunks: Vec<_> = iter.remaining_chunks().collect();
+    assert_eq!(chunks, [&[0, 1, 2][..], &[3, 4, 5], &[6, 7]]);
+    iter.nth(3);
+    let chunks: Vec<_> = iter.remaining_chunks().collect();
+    assert_eq!(chunks, [&[4, 5][..], &[6, 7]]);
+    iter.nth(1);
+    let chunks: Vec<_> = iter.remaining_chunks().collect();
+    assert_eq!(chunks, [&[6, 7][..]]);
+    iter.nth(1);
+    assert_eq!(iter.remaining_chunks().count(), 0);
+
+    arena.alloc_shared(8);
+    let mut iter = arena.iter();
+    iter.nth(8);
+    assert_eq!(iter.remaining_chunks().count(), 0);
+}
+
+#[test]
+fn nth() {
+    let mut arena = Arena::<_, 3>::new();
+    arena.extend_refs(0..10_u8);
+    for start in 0..=10 {
+        for n in 0..=11 {
+            let mut iter = arena.iter_mut();
+            if start > 0 {
+                iter.nth(start - 1);
+            }
+            let expected = (start..10).nth(n);
+            assert_eq!(iter.nth(n).map(|n| usize::from(*n)), expected);
+            assert!(iter.map(|n| usize::from(*n)).eq((start + n + 1)..10));
+        }
+    }
+}
+
+#[test]
+fn count() {
+    let arena = Arena::<_, 3, true, false>::new();
+    for i in 0..10_u8 {
+        arena.alloc_shared(i);
+    }
+    assert_eq!(arena.iter().count(), 10);
+    assert_eq!(arena.iter().size_hint(), (10, Some(10)));
+
+    let mut iter = arena.iter();
+    iter.next();
+    iter.nth(4);
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.clone().count(), 4);
+
+    let iter = arena.iter_at(&iter.as_position());
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.count(), 4);
+
+    let mut iter = arena.into_iter();
+    iter.nth(6);
+    assert_eq!(iter.count(), 3);
+}
+
+#[test]
+fn exact_size() {
+    let mut arena = Arena::<_, 3, true>::new();
+    arena.extend_refs(0..10_u8);
+    let mut iter = arena.iter_mut();
+    assert_eq!(iter.len(), 10);
+    iter.nth(3);
+    assert_eq!(iter.len(), 6);
+    let position = iter.as_position();
+    drop(iter);
+
+    let mut iter = arena.iter_mut_at(&position);
+    assert_eq!(iter.len(), 6);
+    iter.nth(5);
+    let end = iter.as_position();
+    drop(iter);
+    assert_eq!(arena.iter_mut_at(&end).len(), 0);
+    let last = arena.position_at_index(9).unwrap();
+    assert_eq!(arena.iter_mut_at(&last).len(), 1);
+
+    let mut iter = arena.into_iter();
+    assert_eq!(iter.len(), 10);
+    iter.next();
+    assert_eq!(iter.len(), 9);
+    assert_eq!(iter.collect::<Vec<_>>().capacity(), 9);
+}
+
+#[test]
+fn double_ended() {
+    let mut arena = Arena::<_, 3, true>::new();
+    arena.extend_refs(0..10_u8);
+    assert!(arena.iter_mut().rev().map(|n| *n).eq((0..10).rev()));
+
+    let mut iter = arena.iter_mut();
+    assert_eq!(iter.next_back(), Some(&mut 9));
+    assert_eq!(iter.next(), Some(&mut 0));
+    assert_eq!(iter.nth_back(3), Some(&mut 5));
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.nth(2), Some(&mut 3));
+    assert_eq!(iter.next_back(), Some(&mut 4));
+    assert!(iter.is_at_end());
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let position = arena.position_at_index(4).unwrap();
+    let mut iter = arena.iter_mut_at(&position);
+    assert_eq!(iter.next_back(), Some(&mut 9));
+    assert!(iter.map(|n| *n).eq(4..9));
+    // Refers to the end of the first chunk.
+    let position = arena.position_at_index(3).unwrap();
+    assert!(arena.iter_mut_at(&position).rev().map(|n| *n).eq((3..10).rev()));
+
+    let mut iter = arena.into_iter();
+    assert_eq!(iter.next_back(), Some(9));
+    assert_eq!(iter.next(), Some(0));
+    assert!(iter.rev().eq((1..9).rev()));
+}
+
+#[test]
+fn into_iter_double_ended_drop() {
+    let allocations = live_allocations();
+    let arena = Arena::<_, 3>::new();
+    arena.extend_refs((0..10_u8).map(Box::new));
+    let mut iter = arena.into_iter();
+    assert_eq!(iter.next_back().as_deref(), Some(&9));
+    assert_eq!(iter.nth_back(4).as_deref(), Some(&4));
+    assert_eq!(iter.next().as_deref(), Some(&0));
+    drop(iter);
+    assert_eq!(live_allocations(), allocations);
+}
+
+#[test]
+fn skip_to() {
+    let arena = Arena::<_, 3, true, false>::new();
+    for i in [0, 0, 0, 0, 1, 2, 0_u8].iter().copied() {
+        arena.alloc_shared(i);
+    }
+    let mut iter = arena.iter();
+    let position = iter.skip_to(|&n| n == 0).unwrap();
+    assert_eq!(iter.next(), Some(&1));
+    assert!(arena.iter_at(&position).copied().eq([1, 2, 0]));
+    assert!(iter.skip_to(|&n| n < 5).is_none());
+    assert!(iter.is_at_end());
+}
+
 #[test]
 fn iter_mut() {
     let mut arena = Arena::<_, 4>::new();
@@ -139,6 +927,308 @@ fn iter_mut() {
     assert!(arena.iter_mut().map(|n| *n).eq(0..32));
 }
 
+#[test]
+fn chunks_mut() {
+    let mut arena = Arena::<_, 4>::new();
+    assert_eq!(arena.chunks_mut().count(), 0);
+    arena.extend_refs(0..10_u8);
+    arena.chunks_mut().for_each(<[_]>::reverse);
+    assert!(arena.iter_mut().map(|n| *n).eq([3, 2, 1, 0, 7, 6, 5, 4, 9, 8]));
+}
+
+#[test]
+fn reorder_by_key() {
+    let mut arena = Arena::<_, 3, true>::new();
+    for i in 0..10_u8 {
+        arena.alloc((i % 3, i));
+    }
+    let position = arena.iter_mut().as_position();
+    arena.reorder_by_key(|&(key, _)| key);
+    assert!(arena
+        .iter_mut()
+        .map(|&mut (_, n)| n)
+        .eq([0, 3, 6, 9, 1, 4, 7, 2, 5, 8]));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        arena.iter_mut_at(&position).next();
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn reorder_by_key_panic() {
+    let allocations = live_allocations();
+    let mut arena = Arena::<_, 3>::new();
+    arena.extend_refs((0..10).map(Box::new));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        arena.reorder_by_key(|n| {
+            if **n == 5 {
+                silent_panic();
+            }
+            **n
+        });
+    }));
+    assert!(result.is_err());
+    drop(result);
+    assert!(arena.is_empty());
+    drop(arena);
+    assert_eq!(live_allocations(), allocations);
+}
+
+#[test]
+fn retain() {
+    let mut arena = Arena::<_, 3>::new();
+    let mut scratch = Arena::new();
+    arena.extend_refs((0..10_u8).map(Box::new));
+    scratch.extend_refs((0..4).map(Box::new));
+    arena.retain(&mut scratch, |n| **n % 3 != 0);
+    assert!(arena.iter_mut().map(|n| **n).eq([1, 2, 4, 5, 7, 8]));
+    assert!(scratch.is_empty());
+
+    let allocations = live_allocations();
+    arena.retain(&mut scratch, |n| **n > 4);
+    assert!(arena.iter_mut().map(|n| **n).eq([5, 7, 8]));
+    assert_eq!(live_allocations(), allocations - 3);
+}
+
+#[test]
+fn retain_panic() {
+    let allocations = live_allocations();
+    let mut arena = Arena::<_, 3>::new();
+    let mut scratch = Arena::new();
+    arena.extend_refs((0..10).map(Box::new));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        arena.retain(&mut scratch, |n| {
+            if **n == 5 {
+                silent_panic();
+            }
+            **n % 2 == 0
+        });
+    }));
+    assert!(result.is_err());
+    drop(result);
+    assert!(arena.iter_mut().map(|n| **n).eq([0, 2, 4]));
+    assert!(scratch.is_empty());
+    drop((arena, scratch));
+    assert_eq!(live_allocations(), allocations);
+}
+
+#[test]
+fn get_two_mut() {
+    let mut arena = Arena::<_, 3>::new();
+    arena.extend_refs(0..10_u8);
+    let (a, b) = arena.get_two_mut(8, 1).unwrap();
+    core::mem::swap(a, b);
+    assert!(arena.get_two_mut(4, 4).is_none());
+    assert!(arena.get_two_mut(4, 10).is_none());
+    assert!(arena.iter_mut().map(|n| *n).eq([0, 8, 2, 3, 4, 5, 6, 7, 1, 9]));
+}
+
+#[test]
+fn pairs_mut() {
+    let mut arena = Arena::<_, 3>::new();
+    arena.extend_refs(0..7_u8);
+    for (left, right) in arena.pairs_mut() {
+        core::mem::swap(left, right);
+    }
+    assert!(arena.iter_mut().map(|n| *n).eq([1, 0, 3, 2, 5, 4, 6]));
+    assert_eq!(arena.pairs_mut().count(), 3);
+}
+
+#[test]
+fn iter_mut_as_iter() {
+    let mut arena = Arena::<_, 3>::new();
+    arena.extend_refs(0..5_u8);
+    let mut iter = arena.iter_mut();
+    *iter.next().unwrap() = 10;
+    assert!(iter.as_iter().copied().eq(1..5));
+    let sum: u8 = iter.as_iter().sum();
+    for item in iter {
+        *item += sum;
+    }
+    assert!(arena.into_iter().eq([10, 11, 12, 13, 14]));
+}
+
+#[test]
+fn cursor_mut() {
+    let mut arena = Arena::<_, 2>::new();
+    assert_eq!(arena.cursor_mut().current(), None);
+    arena.extend_refs(0..5_u8);
+
+    let mut cursor = arena.cursor_mut();
+    assert_eq!(cursor.index(), Some(0));
+    for i in 0..5 {
+        assert_eq!(cursor.current().copied(), Some(i));
+        cursor.move_next();
+    }
+    assert_eq!(cursor.index(), None);
+    assert_eq!(cursor.current(), None);
+
+    for i in (0..5_u8).rev() {
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(usize::from(i)));
+        *cursor.current().unwrap() += 10;
+    }
+    cursor.move_prev();
+    assert_eq!(cursor.current(), None);
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&mut 10));
+    assert!(arena.into_iter().eq(10..15));
+}
+
+#[test]
+fn cursor_mut_position() {
+    let mut arena = Arena::<_, 2, true>::new();
+    arena.extend_refs(0..5_u8);
+    let mut cursor = arena.cursor_mut();
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    let position = cursor.position();
+    cursor.move_next();
+    cursor.move_next();
+    let end = cursor.position();
+
+    cursor.move_next();
+    cursor.seek(&position);
+    assert_eq!(cursor.index(), Some(3));
+    assert_eq!(cursor.current(), Some(&mut 3));
+    cursor.seek(&end);
+    assert_eq!(cursor.current(), None);
+    assert!(arena.iter_mut_at(&end).next().is_none());
+    assert!(arena.iter_mut_at(&position).map(|n| *n).eq(3..5));
+}
+
+#[test]
+fn is_at_end() {
+    let mut arena = Arena::<_, 4>::new();
+    assert!(arena.iter_mut().is_at_end());
+    for i in 0..8_u8 {
+        arena.alloc(i);
+    }
+
+    let mut iter = arena.iter_mut();
+    let mut count = 0;
+    while !iter.is_at_end() {
+        assert!(iter.next().is_some());
+        count += 1;
+    }
+    assert_eq!(count, 8);
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn get_mut() {
+    let mut arena = Arena::<_, 4>::new();
+    for i in 0..10_u8 {
+        arena.alloc(i);
+    }
+
+    *arena.get_mut(0).unwrap() += 10;
+    *arena.get_mut(7).unwrap() += 10;
+    assert_eq!(arena.get_mut(9).copied(), Some(9));
+    assert!(arena.get_mut(10).is_none());
+    assert!(arena.iter_mut().map(|n| *n).eq([10, 1, 2, 3, 4, 5, 6, 17, 8, 9]));
+}
+
+#[test]
+fn ensure_index() {
+    let mut arena = Arena::<u8, 3>::new();
+    *arena.ensure_index(4) = 4;
+    assert_eq!(arena.len(), 5);
+    *arena.ensure_index(1) = 1;
+    assert_eq!(arena.len(), 5);
+    assert!(arena.into_iter().eq([0, 1, 0, 0, 4]));
+}
+
+#[test]
+#[should_panic(expected = "cannot hold more than `usize::MAX` items")]
+fn ensure_index_overflow() {
+    Arena::<u8, 3>::new().ensure_index(usize::MAX);
+}
+
+#[test]
+fn index() {
+    type Arena<T> = self::Arena<
+        T,
+        4,     /* CHUNK_SIZE */
+        false, /* SUPPORTS_POSITIONS */
+        false, /* MUTABLE */
+    >;
+
+    let mut arena = Arena::new();
+    for i in 0..10_u8 {
+        arena.alloc_shared(i);
+    }
+
+    arena[4] = 40;
+    arena[9] += 90;
+    assert_eq!(arena[3], 3);
+    assert_eq!(arena[4], 40);
+    assert_eq!(arena[9], 99);
+    assert_eq!(arena.get(4), Some(&40));
+    assert_eq!(arena.get(10), None);
+}
+
+#[test]
+fn clone() {
+    let arena = crate::Arena::<_, 3, false, false>::new();
+    for i in 0..7_u8 {
+        arena.alloc_shared(Box::new(i));
+    }
+    let mut copy = arena.clone();
+    assert!(copy.iter().map(|n| **n).eq(0..7));
+
+    let source = crate::Arena::<_, 3, false, false>::new();
+    for i in 10..15_u8 {
+        source.alloc_shared(Box::new(i));
+    }
+    let allocations = live_allocations();
+    copy.clone_from(&source);
+    // Seven boxes were dropped and five were created, but no chunks were
+    // allocated or freed.
+    assert_eq!(live_allocations(), allocations - 2);
+    assert!(copy.iter().map(|n| **n).eq(10..15));
+    assert!(arena.iter().map(|n| **n).eq(0..7));
+}
+
+#[test]
+fn handles() {
+    struct Node {
+        value: u8,
+        next: Option<crate::arena::Ref<Node>>,
+    }
+
+    let mut arena = crate::Arena::<_, 2, false, false>::new();
+    let mut next = None;
+    for value in 0..5 {
+        next = Some(arena.alloc_handle(Node {
+            value,
+            next,
+        }));
+    }
+
+    let head = next.unwrap();
+    assert_eq!(head.index(), 4);
+    arena.deref_handle_mut(head).value = 40;
+    let mut values = Vec::new();
+    let mut next = Some(head);
+    while let Some(handle) = next {
+        let node = arena.deref_handle(handle);
+        values.push(node.value);
+        next = node.next;
+    }
+    assert_eq!(values, [40, 3, 2, 1, 0]);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn index_out_of_bounds() {
+    let arena = crate::Arena::<u8, 4, false, false>::new();
+    arena.alloc_shared(0);
+    let _ = arena[1];
+}
+
 #[test]
 fn into_iter() {
     let arena = Arena::<_, 5>::new();
@@ -170,6 +1260,140 @@ fn position() {
     assert!(arena.iter_mut_at(&pos2).map(|n| *n).eq(22..48));
 }
 
+#[test]
+fn position_at_index() {
+    let arena = Arena::<_, 3, true, false>::new();
+    assert!(arena
+        .iter_at(&arena.position_at_index(0).unwrap())
+        .next()
+        .is_none());
+    for i in 0..6_u8 {
+        arena.alloc_shared(i);
+    }
+    for i in 0..=6 {
+        let position = arena.position_at_index(usize::from(i)).unwrap();
+        assert!(arena.iter_at(&position).copied().eq(i..6));
+    }
+    assert!(arena.position_at_index(7).is_none());
+    let position = arena.position_at_index(6).unwrap();
+    arena.alloc_shared(6);
+    assert!(arena.iter_at(&position).copied().eq([6]));
+}
+
+#[test]
+fn get_at() {
+    let mut arena = Arena::<_, 3, true, false>::new();
+    arena.alloc_extend(0..8_u8);
+    let mut iter = arena.iter();
+    let mut positions = Vec::new();
+    loop {
+        positions.push(iter.as_position());
+        if iter.next().is_none() {
+            break;
+        }
+    }
+
+    for i in [5, 0, 7, 3, 2, 6, 1, 4_u8] {
+        assert_eq!(arena.get_at(&positions[usize::from(i)]), Some(&i));
+    }
+    assert_eq!(arena.get_at(&positions[8]), None);
+    *arena.get_at_mut(&positions[3]).unwrap() = 100;
+    assert_eq!(arena.get_at_mut(&positions[8]), None);
+    arena.alloc_shared(8);
+    assert_eq!(arena.get_at(&positions[8]), Some(&8));
+    assert_eq!(arena.get(3), Some(&100));
+}
+
+#[test]
+fn position_ord() {
+    let arena = Arena::<_, 3, true, false>::new();
+    let start = arena.position_at_index(0).unwrap();
+    arena.alloc_extend(0..8_u8);
+    let mut positions: Vec<_> = [6, 2, 8, 0, 5, 3]
+        .iter()
+        .map(|&i| arena.position_at_index(i).unwrap())
+        .collect();
+    positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!(positions
+        .iter()
+        .map(|p| arena.get_at(p))
+        .eq([0, 2, 3, 5, 6].iter().map(Some).chain([None])));
+    assert!(positions[1] < positions[2]);
+
+    // `start` was obtained before the arena allocated any chunks.
+    assert!(start != positions[0]);
+    assert_eq!(start.partial_cmp(&positions[0]), None);
+
+    let mut iter = arena.iter();
+    iter.nth(5);
+    assert!(iter.as_position() == positions[4]);
+
+    let other = Arena::<_, 3, true, false>::new();
+    other.alloc_extend(0..8_u8);
+    let other_position = other.position_at_index(0).unwrap();
+    assert!(other_position != positions[0]);
+    assert_eq!(other_position.partial_cmp(&positions[0]), None);
+}
+
+#[test]
+fn position_distance() {
+    let arena = Arena::<_, 3, true, false>::new();
+    let start = arena.position_at_index(0).unwrap();
+    arena.alloc_extend(0..4_u8);
+    let mut iter = arena.iter();
+    iter.nth(3);
+    let mark = iter.as_position();
+    arena.alloc_extend(4..11);
+    let end = arena.position_at_index(11).unwrap();
+    assert_eq!(arena.position_distance(&start, &mark), 4);
+    assert_eq!(arena.position_distance(&end, &mark), 7);
+    assert_eq!(arena.position_distance(&start, &end), arena.len());
+    assert_eq!(arena.position_distance(&end, &end), 0);
+
+    let other = Arena::<_, 3, true, false>::new();
+    other.alloc_shared(0);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        other.position_distance(&start, &mark)
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn alloc_with_position() {
+    let mut arena = Arena::<_, 3, true>::new();
+    let mut positions = Vec::new();
+    for i in 0..10_u8 {
+        if i % 3 == 0 {
+            arena.alloc(100 + i);
+        }
+        let (item, position) = arena.alloc_with_position(i);
+        *item += 10;
+        positions.push(position);
+    }
+    for (i, position) in (10..20).zip(&positions).rev() {
+        assert_eq!(arena.iter_mut_at(position).next().copied(), Some(i));
+    }
+    assert!(arena.position_at_index(5).unwrap() == positions[3]);
+}
+
+#[test]
+fn replace_at() {
+    let mut arena = Arena::<_, 4, true>::new();
+    for i in 0..10_u8 {
+        arena.alloc(i);
+    }
+
+    let mut iter = arena.iter_mut();
+    iter.nth(3);
+    let pos = iter.as_position();
+    iter.nth(5);
+    let end = iter.as_position();
+
+    assert_eq!(arena.replace_at(&pos, 100), Some(4));
+    assert_eq!(arena.replace_at(&end, 101), None);
+    assert!(arena.iter_mut().map(|n| *n).eq([0, 1, 2, 3, 100, 5, 6, 7, 8, 9]));
+}
+
 #[test]
 #[should_panic]
 fn bad_position() {
@@ -189,3 +1413,173 @@ fn bad_position() {
     }
     arena.iter_mut_at(&pos);
 }
+
+#[test]
+fn uninit() {
+    let mut arena = UninitArena::<u32>::new();
+    for i in 0..20 {
+        arena.alloc_uninit().write(i);
+    }
+    for item in &mut arena {
+        let value = unsafe { item.assume_init_mut() };
+        *value *= 2;
+    }
+    assert_eq!(arena.len(), 20);
+    let iter = unsafe { arena.iter_assume_init() };
+    assert!(iter.copied().eq((0..20).map(|n| n * 2)));
+}
+
+#[test]
+fn iter_indexed() {
+    let arena = Arena::<_, 4, false, false>::new();
+    for i in 0..10_u8 {
+        arena.alloc_shared(i * 2);
+    }
+    assert!(arena.iter_indexed().all(|(i, n)| usize::from(*n) == i * 2));
+    assert_eq!(arena.iter_indexed().next_back(), Some((9, &18)));
+}
+
+#[test]
+fn hash() {
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let a = Arena::<_, 4, false, false>::new();
+    let b = Arena::<_, 8, false, false>::new();
+    for i in 0..10_u8 {
+        a.alloc_shared(i);
+        b.alloc_shared(i);
+    }
+    assert_eq!(hash(&a), hash(&b));
+    b.alloc_shared(10);
+    assert_ne!(hash(&a), hash(&b));
+}
+
+#[test]
+fn static_arena() {
+    let allocations = live_allocations();
+    let mut arena = StaticArena::<_, 3>::new();
+    let item = arena.alloc(Box::new(1)).unwrap();
+    **item += 10;
+    arena.alloc(Box::new(2)).unwrap();
+    arena.alloc(Box::new(3)).unwrap();
+    assert!(arena.alloc(Box::new(4)).is_none());
+    assert_eq!(arena.len(), 3);
+    assert!(arena.iter_mut().map(|n| **n).eq([11, 2, 3]));
+    assert!(arena.iter().rev().map(|n| **n).eq([3, 2, 11]));
+    assert_eq!(arena.iter().len(), 3);
+    assert_eq!(*arena.as_slice()[1], 2);
+    drop(arena);
+    assert_eq!(live_allocations(), allocations);
+}
+
+#[test]
+fn static_arena_static() {
+    static ARENA: StaticArena<usize, 8> = StaticArena::new();
+    let threads: Vec<_> = (0..4)
+        .map(|_| std::thread::spawn(|| ARENA.alloc(1).unwrap()))
+        .collect();
+    let items: Vec<_> =
+        threads.into_iter().map(|t| t.join().unwrap()).collect();
+    for (i, item) in items.into_iter().enumerate() {
+        *item += i;
+    }
+    assert_eq!(ARENA.len(), 4);
+    assert!((4..8).all(|i| ARENA.alloc(i).is_some()));
+    assert!(ARENA.alloc(8).is_none());
+    assert_eq!(ARENA.len(), 8);
+}
+
+#[cfg(feature = "allocator_api")]
+#[test]
+fn custom_allocator() {
+    use crate::options::TypedOptions;
+    use allocator_api2::alloc::{AllocError, Allocator};
+    use core::ptr::{self, NonNull};
+    use integral_constant::{Bool, Usize};
+
+    std::thread_local! {
+        static CHUNKS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Default)]
+    struct ChunkCounter;
+
+    // SAFETY: This type forwards to `System`.
+    unsafe impl Allocator for ChunkCounter {
+        fn allocate(
+            &self,
+            layout: Layout,
+        ) -> Result<NonNull<[u8]>, AllocError> {
+            CHUNKS.with(|n| n.set(n.get() + 1));
+            // SAFETY: Chunks always have non-zero size.
+            let ptr = unsafe { System.alloc(layout) };
+            NonNull::new(ptr::slice_from_raw_parts_mut(ptr, layout.size()))
+                .ok_or(AllocError)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            CHUNKS.with(|n| n.set(n.get() - 1));
+            // SAFETY: Checked by caller.
+            unsafe { System.dealloc(ptr.as_ptr(), layout) }
+        }
+    }
+
+    type Options = TypedOptions<
+        Usize<4>,
+        Bool<false>,
+        Bool<true>,
+        Usize<{ usize::MAX }>,
+        Bool<false>,
+        Bool<false>,
+        ChunkCounter,
+    >;
+
+    let arena = crate::arena::Arena::<_, Options>::new();
+    arena.alloc_extend((0..10).map(Box::new));
+    assert_eq!(CHUNKS.with(Cell::get), 3);
+    let mut iter = arena.into_iter();
+    assert!(iter.by_ref().take(5).map(|n| *n).eq(0..5));
+    assert_eq!(CHUNKS.with(Cell::get), 2);
+    drop(iter);
+    assert_eq!(CHUNKS.with(Cell::get), 0);
+
+    // The new arena uses the same allocator.
+    let arena = crate::arena::Arena::<_, Options>::new();
+    arena.alloc_extend((0..10).map(Box::new));
+    let arena = arena.rechunk::<8>();
+    assert_eq!(CHUNKS.with(Cell::get), 2);
+    assert!(arena.into_iter().map(|n| *n).eq(0..10));
+    assert_eq!(CHUNKS.with(Cell::get), 0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    use crate::ManuallyDropArena;
+    use std::string::String;
+
+    let arena = Arena::<_, 3, false, false>::new();
+    arena.alloc_extend(0..8_u32);
+    let json = serde_json::to_string(&arena).unwrap();
+    assert_eq!(json, "[0,1,2,3,4,5,6,7]");
+
+    let copy: Arena<u32, 3, false, false> =
+        serde_json::from_str(&json).unwrap();
+    assert!(copy.iter().eq(arena.iter()));
+    assert_eq!(copy.chunk_count(), 3);
+
+    let mut copy: ManuallyDropArena<u32, 3> =
+        serde_json::from_str(&json).unwrap();
+    assert!(unsafe { copy.iter_unchecked() }.eq(arena.iter()));
+    unsafe {
+        copy.drop();
+    }
+
+    let allocations = live_allocations();
+    assert!(serde_json::from_str::<Arena<String>>(r#"["a", "b", 3]"#).is_err());
+    assert_eq!(live_allocations(), allocations);
+}
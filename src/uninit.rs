@@ -0,0 +1,126 @@
+/*
+ * Copyright (C) 2022 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of fixed-typed-arena.
+ *
+ * fixed-typed-arena is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * fixed-typed-arena is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with fixed-typed-arena. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! An arena of items that are initialized after allocation.
+
+use super::arena::Arena;
+use super::iter::IterMut;
+use super::ArenaOptions;
+use core::mem::MaybeUninit;
+use integral_constant::Bool;
+
+/// An arena that allocates uninitialized items of type `T`.
+///
+/// This is a thin wrapper around an [`Arena`] of [`MaybeUninit<T>`]. Note
+/// that, like [`MaybeUninit`] itself, the arena never drops its items, even
+/// if they have been initialized.
+pub struct UninitArena<
+    T,
+    Options: ArenaOptions<MaybeUninit<T>> = super::Options,
+>(Arena<MaybeUninit<T>, Options>);
+
+impl<T, Options> Default for UninitArena<T, Options>
+where
+    Options: ArenaOptions<MaybeUninit<T>>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Options> UninitArena<T, Options>
+where
+    Options: ArenaOptions<MaybeUninit<T>>,
+{
+    /// Creates a new [`UninitArena`].
+    pub fn new() -> Self {
+        Self(Arena::new())
+    }
+
+    /// Returns the total number of items that have been allocated.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks whether the arena is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Allocates a new uninitialized item in the arena. Returns a reference
+    /// to the allocated item.
+    ///
+    /// This method calls [`handle_alloc_error`] if memory allocation fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_uninit(&self) -> &mut MaybeUninit<T>
+    where
+        Options: ArenaOptions<
+            MaybeUninit<T>,
+            Mutable = Bool<true>,
+            Pinned = Bool<false>,
+        >,
+    {
+        self.0.alloc(MaybeUninit::uninit())
+    }
+
+    /// Returns a mutable iterator over the (possibly uninitialized) items in
+    /// this arena.
+    pub fn iter_mut(&mut self) -> IterMut<'_, MaybeUninit<T>, Options>
+    where
+        Options: ArenaOptions<MaybeUninit<T>, Pinned = Bool<false>>,
+    {
+        self.0.iter_mut()
+    }
+
+    /// Returns an iterator over the items in this arena, assuming they have
+    /// all been initialized.
+    ///
+    /// # Safety
+    ///
+    /// * Every item in the arena must be initialized.
+    /// * There must be no mutable references (or references derived from
+    ///   mutable references) to items (or parts of items) in this arena or
+    ///   instances of [`IterMut`] for this arena.
+    pub unsafe fn iter_assume_init(&self) -> impl Iterator<Item = &T> + '_ {
+        // SAFETY: Checked by caller.
+        unsafe { self.0.iter_unchecked() }.map(|item| {
+            // SAFETY: Caller guarantees every item is initialized.
+            unsafe { item.assume_init_ref() }
+        })
+    }
+
+    /// Returns the underlying [`Arena`].
+    pub fn into_inner(self) -> Arena<MaybeUninit<T>, Options> {
+        self.0
+    }
+}
+
+impl<'a, T, Options> IntoIterator for &'a mut UninitArena<T, Options>
+where
+    Options: ArenaOptions<MaybeUninit<T>, Pinned = Bool<false>>,
+{
+    type IntoIter = IterMut<'a, MaybeUninit<T>, Options>;
+    type Item = &'a mut MaybeUninit<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}